@@ -25,12 +25,19 @@
 pub mod ascii;
 pub use ascii::{AnimatedSprite, Sprite, Text};
 
+pub mod collision;
+
 pub mod containers;
 pub use containers::PixelContainer;
 
 pub mod geometry;
 pub use geometry::{Line, Polygon, Rect, Triangle};
 
+pub mod tilemap;
+pub use tilemap::Tilemap;
+
+pub mod ui;
+
 pub mod view;
 #[allow(deprecated)]
 pub use view::{Pixel, Point, Vec2D, View};
@@ -9,9 +9,21 @@ pub use sprite::Sprite;
 mod text;
 pub use text::Text;
 
+mod text_box;
+pub use text_box::{Overflow, TextBox, TextWrap};
+
+mod figlet;
+pub use figlet::FigletFont;
+
 mod alignment;
 pub use alignment::{TextAlign, TextAlign2D};
 
+mod markup;
+pub use markup::{parse_markup, StyledSpan};
+
+mod mirror;
+pub use mirror::{MirrorTable, TextureTransform};
+
 /// Remove all leading newlines from the string
 #[must_use]
 pub fn remove_leading_newlines(texture: &str) -> String {
@@ -21,15 +21,25 @@ impl TextAlign {
             Self::End => pos - text_length,
         }
     }
+
+    /// Return the offset at which content of the given length should start to be aligned within a space of the given length, such as a line of text within a box
+    #[must_use]
+    pub const fn offset_within(&self, space_length: isize, content_length: isize) -> isize {
+        match self {
+            Self::Begin => 0,
+            Self::Centered => (space_length - content_length) / 2,
+            Self::End => space_length - content_length,
+        }
+    }
 }
 
 /// Two-dimensional text align, used by [`Sprite`](super::Sprite) and all variations
 #[derive(Debug, Clone, Copy)]
 pub struct TextAlign2D {
     /// X coordinate [`TextAlign`]. `TextAlign::Begin` is left
-    x: TextAlign,
+    pub x: TextAlign,
     /// Y coordinate [`TextAlign`]. `TextAlign::Begin` is top
-    y: TextAlign,
+    pub y: TextAlign,
 }
 
 impl Default for TextAlign2D {
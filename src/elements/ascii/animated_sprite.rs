@@ -1,6 +1,6 @@
-use super::{remove_leading_newlines, Sprite, TextAlign2D};
+use super::{remove_leading_newlines, MirrorTable, Sprite, TextAlign2D, TextureTransform};
 use crate::elements::{
-    view::{Modifier, ViewElement},
+    view::{Area, Modifier, ViewElement},
     Pixel, Vec2D,
 };
 
@@ -42,6 +42,18 @@ impl AnimatedSprite {
         self.current_frame %= self.frames.len();
     }
 
+    /// Flip or rotate every frame of the `AnimatedSprite`, mirroring characters like `/` and `┌` using the built-in [`MirrorTable`]
+    pub fn transform(&mut self, transform: TextureTransform) {
+        self.transform_with_table(transform, MirrorTable::builtin());
+    }
+
+    /// Flip or rotate every frame of the `AnimatedSprite`, mirroring characters using your own [`MirrorTable`]
+    pub fn transform_with_table(&mut self, transform: TextureTransform, table: &MirrorTable) {
+        for frame in &mut self.frames {
+            *frame = table.transform_texture(frame, transform);
+        }
+    }
+
     /// Returns true if the `current_frame` property is within range of the list of frames. Also returns false if the list of frames is empty
     #[must_use]
     pub fn is_within_frame_range(&self) -> bool {
@@ -58,6 +70,15 @@ impl ViewElement for AnimatedSprite {
             self.frames.len()
         );
 
-        Sprite::draw(self.pos, &self.frames[self.current_frame], self.modifier)
+        Sprite::draw_with_align(
+            self.pos,
+            &self.frames[self.current_frame],
+            self.align,
+            self.modifier,
+        )
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        Sprite::texture_area(self.pos, self.frames.get(self.current_frame)?, self.align)
     }
 }
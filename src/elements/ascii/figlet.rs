@@ -0,0 +1,375 @@
+use super::Sprite;
+use crate::elements::{view::Modifier, Vec2D};
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+
+const BLOCK_FONT: &str = include_str!("figlet/block.flf");
+const HALF_BLOCK_FONT: &str = include_str!("figlet/half_block.flf");
+
+/// The German characters every Figlet font defines after the standard ASCII characters, in order
+const DEUTSCH_CHARS: [char; 7] = ['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'];
+
+/// Horizontal layout flags, as defined in the Figlet font specification
+mod layout {
+    pub const EQUAL: u32 = 1;
+    pub const LOWLINE: u32 = 2;
+    pub const HIERARCHY: u32 = 4;
+    pub const PAIR: u32 = 8;
+    pub const BIG_X: u32 = 16;
+    pub const HARDBLANK: u32 = 32;
+    pub const KERN: u32 = 64;
+    pub const SMUSH: u32 = 128;
+    /// All the smushing rule bits
+    pub const RULES: u32 = 63;
+}
+
+/// A Figlet font, used to render large banner text out of ASCII characters. You can load any standard Figlet `.flf` font file with [`FigletFont::from_file()`] or parse one from a string with [`FigletFont::from_str()`], or use one of the fonts bundled with Gemini, [`FigletFont::block()`] and [`FigletFont::half_block()`]
+///
+/// Characters are joined together using the font's own kerning and smushing rules
+/// ```
+/// use gemini_engine::elements::{ascii::FigletFont, view::Modifier, Vec2D};
+///
+/// let font = FigletFont::half_block();
+/// let title = font.sprite(Vec2D::new(5, 2), "Hi!", Modifier::None);
+///
+/// assert_eq!(title.texture, "█ █ ▀ █ \n█▀█ █ ▀ \n▀ ▀ ▀ ▀ ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FigletFont {
+    hardblank: char,
+    height: usize,
+    layout: u32,
+    glyphs: HashMap<char, Vec<Vec<char>>>,
+}
+
+impl FromStr for FigletFont {
+    type Err = String;
+
+    /// Parse the contents of a Figlet `.flf` font file
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let header = lines.next().ok_or("Font file is empty")?;
+        let Some(header) = header.strip_prefix("flf2a") else {
+            return Err(String::from(
+                "Missing flf2a signature, this is not a Figlet font file",
+            ));
+        };
+        let mut header_chars = header.chars();
+        let hardblank = header_chars
+            .next()
+            .ok_or("Font header is missing the hardblank character")?;
+        let params = header_chars
+            .as_str()
+            .split_whitespace()
+            .map(str::parse::<i64>)
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|_| String::from("Could not parse font header parameters"))?;
+
+        if params.len() < 5 {
+            return Err(String::from(
+                "Font header must contain at least the height, baseline, max length, old layout and comment line count",
+            ));
+        }
+        let height =
+            usize::try_from(params[0]).map_err(|_| String::from("Font height must be positive"))?;
+        let comment_lines = usize::try_from(params[4])
+            .map_err(|_| String::from("Comment line count must be positive"))?;
+        let layout = match (params.get(6), params[3]) {
+            (Some(full_layout), _) => u32::try_from(*full_layout).unwrap_or(0),
+            (None, -1) => 0,
+            (None, 0) => layout::KERN,
+            (None, old_layout) => layout::SMUSH | u32::try_from(old_layout).unwrap_or(0),
+        };
+
+        let mut lines = lines.skip(comment_lines).peekable();
+        let mut glyphs = HashMap::new();
+
+        let read_glyph = |lines: &mut dyn Iterator<Item = &str>| -> Option<Vec<Vec<char>>> {
+            let mut rows = Vec::with_capacity(height);
+            for _ in 0..height {
+                let line = lines.next()?.trim_end_matches(['\r', '\n']);
+                let endmark = line.chars().last().unwrap_or(' ');
+                rows.push(line.trim_end_matches(endmark).chars().collect());
+            }
+            Some(rows)
+        };
+
+        for code in (32u8..127).map(char::from).chain(DEUTSCH_CHARS) {
+            match read_glyph(&mut lines) {
+                Some(glyph) => {
+                    glyphs.insert(code, glyph);
+                }
+                None if code.is_ascii() => {
+                    return Err(format!(
+                        "Font file ended before character {code:?} was defined"
+                    ));
+                }
+                None => break,
+            }
+        }
+
+        while let Some(tag_line) = lines.next() {
+            let tag = tag_line.split_whitespace().next().unwrap_or("");
+            let code = match tag.strip_prefix("0x").or_else(|| tag.strip_prefix("0X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None if tag.len() > 1 && tag.starts_with('0') => {
+                    u32::from_str_radix(&tag[1..], 8).ok()
+                }
+                None => tag.parse::<u32>().ok(),
+            };
+
+            let glyph = read_glyph(&mut lines)
+                .ok_or_else(|| format!("Font file ended while reading character {tag}"))?;
+            // Negative or otherwise invalid codes are allowed by the spec but can't be typed, so they are skipped
+            if let Some(code) = code.and_then(char::from_u32) {
+                glyphs.insert(code, glyph);
+            }
+        }
+
+        Ok(Self {
+            hardblank,
+            height,
+            layout,
+            glyphs,
+        })
+    }
+}
+
+impl FigletFont {
+    /// Load a Figlet font from a `.flf` file
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be read or is not a valid Figlet font
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        contents.parse()
+    }
+
+    /// A bundled font with 5 row tall characters made of solid `█` blocks
+    ///
+    /// # Panics
+    /// Will not panic, the bundled font is always valid
+    #[must_use]
+    pub fn block() -> Self {
+        BLOCK_FONT.parse().expect("Bundled block font is invalid")
+    }
+
+    /// A bundled font with 3 row tall characters made of `▀`, `▄` and `█` half blocks. This is the same as [`FigletFont::block()`] but squashed vertically, which makes up for terminal characters being taller than they are wide
+    ///
+    /// # Panics
+    /// Will not panic, the bundled font is always valid
+    #[must_use]
+    pub fn half_block() -> Self {
+        HALF_BLOCK_FONT
+            .parse()
+            .expect("Bundled half block font is invalid")
+    }
+
+    /// The height of every character in the font
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns true if the font contains a character for the given `char`
+    #[must_use]
+    pub fn has_char(&self, c: char) -> bool {
+        self.glyphs.contains_key(&c)
+    }
+
+    /// Smush two characters into one as dictated by the font's layout rules, returning `None` if they can't be smushed
+    fn smush(&self, left: char, right: char, widths: (usize, usize)) -> Option<char> {
+        const HIERARCHY: [&str; 6] = ["|", "/\\", "[]", "{}", "()", "<>"];
+
+        if left == ' ' {
+            return Some(right);
+        }
+        if right == ' ' {
+            return Some(left);
+        }
+        if widths.0 < 2 || widths.1 < 2 || self.layout & layout::SMUSH == 0 {
+            return None;
+        }
+
+        if self.layout & layout::RULES == 0 {
+            // Universal smushing
+            return Some(if right == self.hardblank { left } else { right });
+        }
+
+        if left == self.hardblank || right == self.hardblank {
+            let both = left == self.hardblank && right == self.hardblank;
+            return (both && self.layout & layout::HARDBLANK != 0).then_some(left);
+        }
+
+        if self.layout & layout::EQUAL != 0 && left == right {
+            return Some(left);
+        }
+        if self.layout & layout::LOWLINE != 0 {
+            if left == '_' && "|/\\[]{}()<>".contains(right) {
+                return Some(right);
+            }
+            if right == '_' && "|/\\[]{}()<>".contains(left) {
+                return Some(left);
+            }
+        }
+        if self.layout & layout::HIERARCHY != 0 {
+            let class = |c: char| HIERARCHY.iter().position(|class| class.contains(c));
+            if let (Some(left_class), Some(right_class)) = (class(left), class(right)) {
+                if left_class != right_class {
+                    return Some(if left_class > right_class {
+                        left
+                    } else {
+                        right
+                    });
+                }
+            }
+        }
+        if self.layout & layout::PAIR != 0
+            && ["[]", "][", "{}", "}{", "()", ")("]
+                .contains(&String::from_iter([left, right]).as_str())
+        {
+            return Some('|');
+        }
+        if self.layout & layout::BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => (),
+            }
+        }
+
+        None
+    }
+
+    /// Work out how many columns the glyph can be moved left into the output
+    fn smush_amount(
+        &self,
+        output: &[Vec<char>],
+        glyph: &[Vec<char>],
+        widths: (usize, usize),
+    ) -> usize {
+        if self.layout & (layout::SMUSH | layout::KERN) == 0 {
+            return 0;
+        }
+
+        let mut amount = widths.1;
+        for (output_row, glyph_row) in output.iter().zip(glyph) {
+            let output_edge = output_row.iter().rposition(|c| *c != ' ');
+            let glyph_edge = glyph_row
+                .iter()
+                .position(|c| *c != ' ')
+                .unwrap_or(glyph_row.len());
+
+            let mut row_amount = glyph_edge + output_row.len() - output_edge.map_or(0, |e| e + 1);
+            if let (Some(edge), Some(right)) = (output_edge, glyph_row.get(glyph_edge)) {
+                if self.smush(output_row[edge], *right, widths).is_some() {
+                    row_amount += 1;
+                }
+            }
+            amount = amount.min(row_amount);
+        }
+
+        amount
+    }
+
+    /// Render a single line of text with the font
+    fn render_line(&self, text: &str) -> Vec<String> {
+        let mut output: Vec<Vec<char>> = vec![vec![]; self.height];
+        let mut previous_width = 0;
+
+        for glyph in text.chars().filter_map(|c| self.glyphs.get(&c)) {
+            let width = glyph.iter().map(Vec::len).max().unwrap_or(0);
+            let widths = (previous_width, width);
+            let amount = self.smush_amount(&output, glyph, widths);
+
+            for (output_row, glyph_row) in output.iter_mut().zip(glyph) {
+                for (k, right) in glyph_row.iter().take(amount).enumerate() {
+                    if let Some(column) = (output_row.len() + k).checked_sub(amount) {
+                        let left = output_row[column];
+                        output_row[column] = self.smush(left, *right, widths).unwrap_or(*right);
+                    }
+                }
+                output_row.extend(glyph_row.iter().skip(amount));
+            }
+
+            previous_width = width;
+        }
+
+        output
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|c| if c == self.hardblank { ' ' } else { c })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Render the text with the font as a multi-line string. Newlines in the text start a new row of Figlet characters, and characters not in the font are skipped
+    #[must_use]
+    pub fn render(&self, text: &str) -> String {
+        text.split('\n')
+            .flat_map(|line| self.render_line(line))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Render the text with the font into a [`Sprite`], which can then be aligned with [`Sprite::with_align()`]
+    #[must_use]
+    pub fn sprite(&self, pos: Vec2D, text: &str, modifier: Modifier) -> Sprite {
+        Sprite::new(pos, &self.render(text), modifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a font with the given layout where every printable character is blank except the provided glyphs
+    fn test_font(full_layout: u32, glyphs: &[(char, [&str; 2])]) -> FigletFont {
+        let mut font = format!("flf2a$ 2 2 8 0 0 0 {full_layout}\n");
+        for c in (32u8..127).map(char::from) {
+            let rows = glyphs
+                .iter()
+                .find(|(g, _)| *g == c)
+                .map_or(["$", "$"], |(_, rows)| *rows);
+            font.extend([rows[0], "@\n", rows[1], "@@\n"]);
+        }
+
+        font.parse().expect("Test font should be valid")
+    }
+
+    #[test]
+    fn full_width_and_kerning() {
+        let glyphs = [('a', ["/ ", "/ "]), ('b', [" \\", " \\"])];
+
+        assert_eq!(test_font(0, &glyphs).render("ab"), "/  \\\n/  \\");
+        assert_eq!(test_font(layout::KERN, &glyphs).render("ab"), "/\\\n/\\");
+    }
+
+    #[test]
+    fn smushing_rules() {
+        let glyphs = [
+            ('a', ["_|", "_|"]),
+            ('b', ["/ ", "\\ "]),
+            ('c', ["\\ ", "/ "]),
+        ];
+
+        let big_x = test_font(layout::SMUSH | layout::BIG_X, &glyphs);
+        assert_eq!(big_x.render("bc"), "| \nY ");
+        assert_eq!(big_x.render("cb"), "Y \n| ");
+
+        let hierarchy = test_font(layout::SMUSH | layout::HIERARCHY, &glyphs);
+        assert_eq!(hierarchy.render("ab"), "_/ \n_\\ ");
+        assert_eq!(big_x.render("ab"), "_|/ \n_|\\ ");
+    }
+
+    #[test]
+    fn bundled_fonts_parse() {
+        assert_eq!(FigletFont::block().height(), 5);
+        assert_eq!(FigletFont::half_block().height(), 3);
+        assert!(FigletFont::block().has_char('ß'));
+    }
+}
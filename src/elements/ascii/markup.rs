@@ -0,0 +1,238 @@
+use crate::elements::view::{Colour, Modifier};
+
+/// A range of characters in a piece of text that should be displayed with a specific [`Modifier`]. Generated by [`parse_markup()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyledSpan {
+    /// The index of the first character the span applies to
+    pub start: usize,
+    /// The index after the last character the span applies to
+    pub end: usize,
+    /// The modifier applied to the characters in the span
+    pub modifier: Modifier,
+}
+
+impl StyledSpan {
+    /// Create a new `StyledSpan` covering the characters from `start` up to (but not including) `end`
+    #[must_use]
+    pub const fn new(start: usize, end: usize, modifier: Modifier) -> Self {
+        Self {
+            start,
+            end,
+            modifier,
+        }
+    }
+
+    /// Returns true if the character at the index is covered by the span
+    #[must_use]
+    pub const fn contains(&self, index: usize) -> bool {
+        self.start <= index && index < self.end
+    }
+
+    /// Return the modifier for the character at the given index. Later spans take priority over earlier ones, so nested spans from [`parse_markup()`] will use the innermost tag. If no span covers the character, `default` is returned
+    #[must_use]
+    pub fn modifier_at(spans: &[Self], index: usize, default: Modifier) -> Modifier {
+        spans
+            .iter()
+            .rev()
+            .find(|span| span.contains(index))
+            .map_or(default, |span| span.modifier)
+    }
+}
+
+/// Return the `Modifier` for a style name used in markup tags. Hex colours are handled separately so that their errors can be passed on
+fn style_from_name(name: &str) -> Option<Modifier> {
+    Some(match name {
+        "black" => Modifier::Coded(30),
+        "red" => Modifier::RED,
+        "green" => Modifier::GREEN,
+        "yellow" => Modifier::YELLOW,
+        "blue" => Modifier::BLUE,
+        "purple" | "magenta" => Modifier::PURPLE,
+        "cyan" => Modifier::CYAN,
+        "white" => Modifier::Coded(37),
+        "bold" => Modifier::Coded(1),
+        "dim" => Modifier::Coded(2),
+        "italic" => Modifier::Coded(3),
+        "underline" => Modifier::Coded(4),
+        "reverse" => Modifier::Coded(7),
+        "strikethrough" => Modifier::Coded(9),
+        _ => return None,
+    })
+}
+
+/// Parse text containing style markup into the plain text and a list of [`StyledSpan`]s to colour it with. Styles are opened with a tag like `[red]` and closed with `[/]` (or `[/red]`, which must match the most recently opened tag). Tags can be nested, in which case the innermost tag is used.
+///
+/// A tag can contain a colour name (`black`, `red`, `green`, `yellow`, `blue`, `purple`/`magenta`, `cyan`, `white`), a text style (`bold`, `dim`, `italic`, `underline`, `reverse`, `strikethrough`) or a hex colour like `#ff8000`. Since a [`Modifier`] can only hold one style at a time, a character can't be both coloured and bold. To write a literal `[` or `]`, double it: `[[` or `]]`
+/// ```
+/// use gemini_engine::elements::{ascii::{parse_markup, StyledSpan}, view::{Colour, Modifier}};
+///
+/// let (text, spans) = parse_markup("[red]warning[/] [#00ff00]now[/] [[ok]]").unwrap();
+///
+/// assert_eq!(text, "warning now [ok]");
+/// assert_eq!(spans, vec![
+///     StyledSpan::new(0, 7, Modifier::RED),
+///     StyledSpan::new(8, 11, Modifier::Colour(Colour::rgb(0, 255, 0))),
+/// ]);
+/// ```
+///
+/// # Errors
+/// Returns an error describing the problem and the character at which it was found if a tag is unknown, unclosed, closed without being opened, closed with the wrong name, or if a bracket is left unescaped
+pub fn parse_markup(markup: &str) -> Result<(String, Vec<StyledSpan>), String> {
+    let mut text = String::new();
+    let mut text_length = 0;
+    let mut spans: Vec<StyledSpan> = vec![];
+    // The name, position in the markup and index into `spans` of every open tag
+    let mut open_tags: Vec<(String, usize, usize)> = vec![];
+
+    let mut chars = markup.chars().enumerate().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '[' if chars.next_if(|(_, next)| *next == '[').is_some() => {
+                text.push('[');
+                text_length += 1;
+            }
+            ']' if chars.next_if(|(_, next)| *next == ']').is_some() => {
+                text.push(']');
+                text_length += 1;
+            }
+            ']' => {
+                return Err(format!(
+                    "Unescaped ']' at character {i}, use ']]' to write a literal bracket"
+                ));
+            }
+            '[' => {
+                let mut tag = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, ']')) => break,
+                        Some((j, '[')) => {
+                            return Err(format!(
+                                "Unexpected '[' at character {j} inside the tag opened at character {i}"
+                            ));
+                        }
+                        Some((_, tag_char)) => tag.push(tag_char),
+                        None => {
+                            return Err(format!(
+                                "Tag opened at character {i} is never closed with a ']'"
+                            ))
+                        }
+                    }
+                }
+                let tag = tag.trim().to_lowercase();
+
+                if let Some(closing_name) = tag.strip_prefix('/') {
+                    let Some((name, _, span_index)) = open_tags.pop() else {
+                        return Err(format!(
+                            "Closing tag [{tag}] at character {i} has no matching opening tag"
+                        ));
+                    };
+                    if !closing_name.is_empty() && closing_name != name {
+                        return Err(format!(
+                            "Closing tag [{tag}] at character {i} does not match the open tag [{name}]"
+                        ));
+                    }
+                    spans[span_index].end = text_length;
+                } else {
+                    let modifier = if let Some(hex) = tag.strip_prefix('#') {
+                        Colour::from_hex(hex)
+                            .map(Modifier::Colour)
+                            .map_err(|error| {
+                                format!("Invalid colour [{tag}] at character {i}: {error}")
+                            })?
+                    } else {
+                        style_from_name(&tag)
+                            .ok_or_else(|| format!("Unknown style [{tag}] at character {i}"))?
+                    };
+                    open_tags.push((tag, i, spans.len()));
+                    spans.push(StyledSpan::new(text_length, text_length, modifier));
+                }
+            }
+            _ => {
+                text.push(c);
+                text_length += 1;
+            }
+        }
+    }
+
+    if let Some((name, i, _)) = open_tags.pop() {
+        return Err(format!(
+            "Tag [{name}] opened at character {i} is never closed"
+        ));
+    }
+
+    spans.retain(|span| span.start < span.end);
+
+    Ok((text, spans))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{Sprite, Vec2D};
+
+    #[test]
+    fn nested_spans_use_innermost() {
+        let (text, spans) =
+            parse_markup("[blue]a[bold]b[/bold]c[/]").expect("Markup should be valid");
+
+        assert_eq!(text, "abc");
+        assert_eq!(
+            StyledSpan::modifier_at(&spans, 0, Modifier::None),
+            Modifier::BLUE
+        );
+        assert_eq!(
+            StyledSpan::modifier_at(&spans, 1, Modifier::None),
+            Modifier::Coded(1)
+        );
+        assert_eq!(
+            StyledSpan::modifier_at(&spans, 2, Modifier::None),
+            Modifier::BLUE
+        );
+        assert_eq!(
+            StyledSpan::modifier_at(&spans, 3, Modifier::None),
+            Modifier::None
+        );
+    }
+
+    #[test]
+    fn sprite_spans_skip_leading_newlines() {
+        let sprite = Sprite::from_markup(Vec2D::ZERO, "[red]\nab[/]cd", Modifier::None)
+            .expect("Markup should be valid");
+
+        assert_eq!(sprite.texture, "abcd");
+        assert_eq!(
+            StyledSpan::modifier_at(&sprite.spans, 1, Modifier::None),
+            Modifier::RED
+        );
+        assert_eq!(
+            StyledSpan::modifier_at(&sprite.spans, 2, Modifier::None),
+            Modifier::None
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_markup("[red]oops").expect_err("Markup should be invalid"),
+            "Tag [red] opened at character 0 is never closed"
+        );
+        assert_eq!(
+            parse_markup("a[/]").expect_err("Markup should be invalid"),
+            "Closing tag [/] at character 1 has no matching opening tag"
+        );
+        assert_eq!(
+            parse_markup("[red]a[/blue]").expect_err("Markup should be invalid"),
+            "Closing tag [/blue] at character 6 does not match the open tag [red]"
+        );
+        assert_eq!(
+            parse_markup("[sparkly]a[/]").expect_err("Markup should be invalid"),
+            "Unknown style [sparkly] at character 0"
+        );
+        assert!(parse_markup("a]").is_err());
+        assert_eq!(
+            parse_markup("a[#12]b[/]").expect_err("Markup should be invalid"),
+            "Invalid colour [#12] at character 1: `12` is not a valid hex colour, it must have 3 or 6 digits"
+        );
+        assert!(parse_markup("[#12345g]a[/]").is_err());
+    }
+}
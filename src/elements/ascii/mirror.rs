@@ -0,0 +1,338 @@
+use super::StyledSpan;
+use crate::elements::view::Modifier;
+use std::{collections::HashMap, sync::OnceLock};
+
+static BUILTIN_TABLE: OnceLock<MirrorTable> = OnceLock::new();
+
+/// Characters that swap with each other when a texture is flipped horizontally
+const HORIZONTAL_PAIRS: &[(char, char)] = &[
+    ('/', '\\'),
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('<', '>'),
+    ('`', '\''),
+    ('d', 'b'),
+    ('p', 'q'),
+    ('┌', '┐'),
+    ('└', '┘'),
+    ('├', '┤'),
+    ('╔', '╗'),
+    ('╚', '╝'),
+    ('╠', '╣'),
+    ('╭', '╮'),
+    ('╰', '╯'),
+    ('┏', '┓'),
+    ('┗', '┛'),
+    ('┣', '┫'),
+    ('╒', '╕'),
+    ('╘', '╛'),
+    ('╓', '╖'),
+    ('╙', '╜'),
+    ('╞', '╡'),
+    ('╟', '╢'),
+    ('╱', '╲'),
+    ('▌', '▐'),
+    ('▖', '▗'),
+    ('▘', '▝'),
+    ('▙', '▟'),
+    ('▛', '▜'),
+    ('◢', '◣'),
+    ('◤', '◥'),
+    ('◀', '▶'),
+    ('←', '→'),
+];
+
+/// Characters that swap with each other when a texture is flipped vertically
+const VERTICAL_PAIRS: &[(char, char)] = &[
+    ('/', '\\'),
+    ('^', 'v'),
+    ('\'', ','),
+    ('┌', '└'),
+    ('┐', '┘'),
+    ('┬', '┴'),
+    ('╔', '╚'),
+    ('╗', '╝'),
+    ('╦', '╩'),
+    ('╭', '╰'),
+    ('╮', '╯'),
+    ('┏', '┗'),
+    ('┓', '┛'),
+    ('┳', '┻'),
+    ('╒', '╘'),
+    ('╕', '╛'),
+    ('╓', '╙'),
+    ('╖', '╜'),
+    ('╤', '╧'),
+    ('╥', '╨'),
+    ('╱', '╲'),
+    ('▀', '▄'),
+    ('▖', '▘'),
+    ('▗', '▝'),
+    ('▙', '▛'),
+    ('▟', '▜'),
+    ('◢', '◥'),
+    ('◣', '◤'),
+    ('▲', '▼'),
+    ('↑', '↓'),
+];
+
+/// Cycles of characters, each one being what the previous one looks like after a clockwise quarter turn
+const CLOCKWISE_CYCLES: &[&[char]] = &[
+    &['-', '|'],
+    &['/', '\\'],
+    &['─', '│'],
+    &['━', '┃'],
+    &['═', '║'],
+    &['╱', '╲'],
+    &['┌', '┐', '┘', '└'],
+    &['┏', '┓', '┛', '┗'],
+    &['╔', '╗', '╝', '╚'],
+    &['╭', '╮', '╯', '╰'],
+    &['├', '┬', '┤', '┴'],
+    &['┣', '┳', '┫', '┻'],
+    &['╠', '╦', '╣', '╩'],
+    &['^', '>', 'v', '<'],
+    &['▲', '▶', '▼', '◀'],
+    &['↑', '→', '↓', '←'],
+    &['▀', '▐', '▄', '▌'],
+    &['▘', '▝', '▗', '▖'],
+    &['◤', '◥', '◢', '◣'],
+];
+
+/// A way to flip or rotate an ASCII texture, used by [`MirrorTable::transform_texture()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureTransform {
+    /// Mirror the texture along its vertical axis, so that the left side becomes the right side
+    FlipHorizontal,
+    /// Mirror the texture along its horizontal axis, so that the top becomes the bottom
+    FlipVertical,
+    /// Rotate the texture 90° clockwise
+    RotateClockwise,
+    /// Rotate the texture 90° anticlockwise
+    RotateAnticlockwise,
+}
+
+/// A table of which characters become which when an ASCII texture is flipped or rotated. Mirroring ASCII art is not just reversing the lines: a `/` has to become a `\`, a `(` a `)`, a `┌` a `┐` and so on.
+///
+/// [`MirrorTable::default()`] comes with pairs for common ASCII and box-drawing characters, and you can add your own with [`add_horizontal_pair()`](MirrorTable::add_horizontal_pair()), [`add_vertical_pair()`](MirrorTable::add_vertical_pair()) and [`add_rotation()`](MirrorTable::add_rotation()). Any character not in the table stays the same
+/// ```
+/// use gemini_engine::elements::ascii::{MirrorTable, TextureTransform};
+///
+/// let table = MirrorTable::default();
+///
+/// assert_eq!(table.transform_texture("(\\_/)", TextureTransform::FlipVertical), "(/‾\\)");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MirrorTable {
+    horizontal: HashMap<char, char>,
+    vertical: HashMap<char, char>,
+    clockwise: HashMap<char, char>,
+    anticlockwise: HashMap<char, char>,
+}
+
+impl Default for MirrorTable {
+    fn default() -> Self {
+        let mut table = Self::empty();
+
+        for (a, b) in HORIZONTAL_PAIRS {
+            table.add_horizontal_pair(*a, *b);
+        }
+        for (a, b) in VERTICAL_PAIRS {
+            table.add_vertical_pair(*a, *b);
+        }
+        table.add_vertical_pair('_', '‾');
+        for cycle in CLOCKWISE_CYCLES {
+            for i in 0..cycle.len() {
+                table.add_rotation(cycle[i], cycle[(i + 1) % cycle.len()]);
+            }
+        }
+
+        table
+    }
+}
+
+impl MirrorTable {
+    /// Create a `MirrorTable` with no character pairs at all, so transforms will only move characters around without changing them
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            horizontal: HashMap::new(),
+            vertical: HashMap::new(),
+            clockwise: HashMap::new(),
+            anticlockwise: HashMap::new(),
+        }
+    }
+
+    /// Return a reference to a shared copy of [`MirrorTable::default()`], so that the built-in table only has to be generated once
+    #[must_use]
+    pub fn builtin() -> &'static Self {
+        BUILTIN_TABLE.get_or_init(Self::default)
+    }
+
+    /// Make the two characters swap with each other when the texture is flipped horizontally
+    pub fn add_horizontal_pair(&mut self, a: char, b: char) {
+        self.horizontal.insert(a, b);
+        self.horizontal.insert(b, a);
+    }
+
+    /// Make the two characters swap with each other when the texture is flipped vertically
+    pub fn add_vertical_pair(&mut self, a: char, b: char) {
+        self.vertical.insert(a, b);
+        self.vertical.insert(b, a);
+    }
+
+    /// Make `from` become `to` when the texture is rotated clockwise, and `to` become `from` when it is rotated anticlockwise
+    pub fn add_rotation(&mut self, from: char, to: char) {
+        self.clockwise.insert(from, to);
+        self.anticlockwise.insert(to, from);
+    }
+
+    /// Return the character as it should appear after the given transform
+    #[must_use]
+    pub fn transform_char(&self, c: char, transform: TextureTransform) -> char {
+        let map = match transform {
+            TextureTransform::FlipHorizontal => &self.horizontal,
+            TextureTransform::FlipVertical => &self.vertical,
+            TextureTransform::RotateClockwise => &self.clockwise,
+            TextureTransform::RotateAnticlockwise => &self.anticlockwise,
+        };
+
+        map.get(&c).copied().unwrap_or(c)
+    }
+
+    /// Flip or rotate a multi-line texture, swapping its characters with their mirrored counterparts. Shorter lines are treated as if they were padded with spaces, and trailing spaces are removed from the result
+    #[must_use]
+    pub fn transform_texture(&self, texture: &str, transform: TextureTransform) -> String {
+        self.transform_styled_texture(texture, &[], transform).0
+    }
+
+    /// Flip or rotate a multi-line texture the same way as [`MirrorTable::transform_texture()`], moving the [`StyledSpan`]s along with the characters they apply to
+    #[must_use]
+    pub fn transform_styled_texture(
+        &self,
+        texture: &str,
+        spans: &[StyledSpan],
+        transform: TextureTransform,
+    ) -> (String, Vec<StyledSpan>) {
+        let mut index = 0;
+        let mut grid: Vec<Vec<(char, Option<Modifier>)>> = vec![];
+        for line in texture.split('\n') {
+            grid.push(
+                (index..)
+                    .zip(line.chars())
+                    .map(|(i, c)| {
+                        (
+                            c,
+                            spans
+                                .iter()
+                                .rev()
+                                .find(|s| s.contains(i))
+                                .map(|s| s.modifier),
+                        )
+                    })
+                    .collect(),
+            );
+            index += line.chars().count() + 1;
+        }
+
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let height = grid.len();
+        let get = |x: usize, y: usize| grid[y].get(x).copied().unwrap_or((' ', None));
+
+        let lines: Vec<Vec<(char, Option<Modifier>)>> = match transform {
+            TextureTransform::FlipHorizontal => (0..height)
+                .map(|y| (0..width).rev().map(|x| get(x, y)).collect())
+                .collect(),
+            TextureTransform::FlipVertical => (0..height)
+                .rev()
+                .map(|y| (0..width).map(|x| get(x, y)).collect())
+                .collect(),
+            TextureTransform::RotateClockwise => (0..width)
+                .map(|x| (0..height).rev().map(|y| get(x, y)).collect())
+                .collect(),
+            TextureTransform::RotateAnticlockwise => (0..width)
+                .rev()
+                .map(|x| (0..height).map(|y| get(x, y)).collect())
+                .collect(),
+        };
+
+        let mut texture = String::new();
+        let mut new_spans: Vec<StyledSpan> = vec![];
+        let mut index = 0;
+        for (y, mut line) in lines.into_iter().enumerate() {
+            while line.last().is_some_and(|(c, _)| *c == ' ') {
+                line.pop();
+            }
+            if y > 0 {
+                texture.push('\n');
+                index += 1;
+            }
+
+            for (c, modifier) in line {
+                texture.push(self.transform_char(c, transform));
+                if let Some(modifier) = modifier {
+                    match new_spans.last_mut() {
+                        Some(span) if span.end == index && span.modifier == modifier => {
+                            span.end += 1;
+                        }
+                        _ => new_spans.push(StyledSpan::new(index, index + 1, modifier)),
+                    }
+                }
+                index += 1;
+            }
+        }
+
+        (texture, new_spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flip_horizontal_mirrors_chars() {
+        let table = MirrorTable::default();
+        let texture = "┌─╮\n│ /\n<=";
+
+        assert_eq!(
+            table.transform_texture(texture, TextureTransform::FlipHorizontal),
+            "╭─┐\n\\ │\n =>"
+        );
+    }
+
+    #[test]
+    fn flip_vertical_reverses_lines() {
+        let table = MirrorTable::default();
+
+        assert_eq!(
+            table.transform_texture("^\n|", TextureTransform::FlipVertical),
+            "|\nv"
+        );
+    }
+
+    #[test]
+    fn rotate_clockwise_and_back() {
+        let table = MirrorTable::default();
+        let texture = "┌─┐\n└─┘";
+
+        let rotated = table.transform_texture(texture, TextureTransform::RotateClockwise);
+        assert_eq!(rotated, "┌┐\n││\n└┘");
+        assert_eq!(
+            table.transform_texture(&rotated, TextureTransform::RotateAnticlockwise),
+            texture
+        );
+    }
+
+    #[test]
+    fn custom_pairs() {
+        let mut table = MirrorTable::empty();
+        table.add_horizontal_pair('L', 'J');
+
+        assert_eq!(
+            table.transform_texture("L/", TextureTransform::FlipHorizontal),
+            "/J"
+        );
+    }
+}
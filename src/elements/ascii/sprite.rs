@@ -1,6 +1,9 @@
-use super::{remove_leading_newlines, Text, TextAlign2D};
+use super::{
+    parse_markup, remove_leading_newlines, MirrorTable, StyledSpan, Text, TextAlign2D,
+    TextureTransform,
+};
 use crate::elements::{
-    view::{Modifier, ViewElement},
+    view::{Area, Modifier, ViewElement},
     Pixel, Vec2D,
 };
 
@@ -16,6 +19,8 @@ pub struct Sprite {
     pub modifier: Modifier,
     /// How the Sprite should align to the position
     pub align: TextAlign2D,
+    /// Ranges of characters in the texture which should use a different [`Modifier`]. Newlines count as characters. You can generate these from markup with [`Sprite::from_markup()`]
+    pub spans: Vec<StyledSpan>,
 }
 
 impl Sprite {
@@ -27,9 +32,35 @@ impl Sprite {
             texture: remove_leading_newlines(texture),
             modifier,
             align: TextAlign2D::default(),
+            spans: vec![],
         }
     }
 
+    /// Create a new `Sprite` from a multi-line string with style markup such as `[red]warning[/] now`. See [`parse_markup()`](super::parse_markup()) for the full syntax. Characters outside of any tag will use the given `modifier`
+    ///
+    /// # Errors
+    /// Returns an error if the markup could not be parsed
+    pub fn from_markup(pos: Vec2D, markup: &str, modifier: Modifier) -> Result<Self, String> {
+        let (texture, spans) = parse_markup(markup)?;
+        let sprite = Self::new(pos, &texture, modifier);
+
+        // `Sprite::new` removes leading newlines, so the spans are moved back by as many characters as it removed
+        let removed = texture.chars().count() - sprite.texture.chars().count();
+        let spans = spans
+            .into_iter()
+            .map(|span| {
+                StyledSpan::new(
+                    span.start.saturating_sub(removed),
+                    span.end.saturating_sub(removed),
+                    span.modifier,
+                )
+            })
+            .filter(|span| span.start < span.end)
+            .collect();
+
+        Ok(Self { spans, ..sprite })
+    }
+
     /// Return the `Sprite` with the modified align property
     #[must_use]
     pub const fn with_align(self, align: TextAlign2D) -> Self {
@@ -38,19 +69,61 @@ impl Sprite {
         tmp
     }
 
+    /// Flip or rotate the `Sprite`'s texture, mirroring characters like `/` and `┌` using the built-in [`MirrorTable`]
+    pub fn transform(&mut self, transform: TextureTransform) {
+        self.transform_with_table(transform, MirrorTable::builtin());
+    }
+
+    /// Flip or rotate the `Sprite`'s texture, mirroring characters using your own [`MirrorTable`]
+    pub fn transform_with_table(&mut self, transform: TextureTransform, table: &MirrorTable) {
+        (self.texture, self.spans) =
+            table.transform_styled_texture(&self.texture, &self.spans, transform);
+    }
+
     /// Render a string texture at a given position in a [`ViewElement::active_pixels()`]-readable format
     #[must_use]
     pub fn draw(pos: Vec2D, texture: &str, modifier: Modifier) -> Vec<Pixel> {
+        Self::draw_styled(pos, texture, &[], modifier)
+    }
+
+    /// Render a string texture at a given position, with characters covered by the [`StyledSpan`]s using the span's [`Modifier`] instead of the default `modifier`
+    #[must_use]
+    pub fn draw_styled(
+        pos: Vec2D,
+        texture: &str,
+        spans: &[StyledSpan],
+        modifier: Modifier,
+    ) -> Vec<Pixel> {
         let mut pixels = vec![];
+        let mut start_index = 0;
 
         let lines = texture.split('\n');
         for (y, line) in (0isize..).zip(lines) {
-            pixels.extend(Text::draw(pos + Vec2D::new(0, y), line, modifier));
+            pixels.extend(Text::draw_from_index(
+                pos + Vec2D::new(0, y),
+                line,
+                start_index,
+                spans,
+                modifier,
+            ));
+            start_index += line.chars().count() + 1;
         }
 
         pixels
     }
 
+    /// Return the width of the longest line and the number of lines in the texture
+    fn content_size(texture: &str) -> Vec2D {
+        Vec2D::new(
+            texture
+                .lines()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0) as isize,
+            texture.lines().count() as isize,
+        )
+    }
+
     /// Return a vector of Pixels to display the given content, aligning the content to the position as directed by the `align` attribute
     #[must_use]
     pub fn draw_with_align(
@@ -59,18 +132,30 @@ impl Sprite {
         align: TextAlign2D,
         modifier: Modifier,
     ) -> Vec<Pixel> {
-        let content_size = Vec2D::new(
-            texture.lines().count() as isize,
-            texture.lines().map(str::len).max().unwrap_or(0) as isize,
-        );
-        let pos = align.apply_to(pos, content_size);
+        let pos = align.apply_to(pos, Self::content_size(texture));
 
         Self::draw(pos, texture, modifier)
     }
+
+    /// Return the [`Area`] covered by the texture once it has been aligned to the position, including any spaces. Returns `None` if the texture is empty
+    #[must_use]
+    pub fn texture_area(pos: Vec2D, texture: &str, align: TextAlign2D) -> Option<Area> {
+        let size = Self::content_size(texture);
+        let area = Area::new(align.apply_to(pos, size), size);
+        (!area.is_empty()).then_some(area)
+    }
 }
 
 impl ViewElement for Sprite {
     fn active_pixels(&self) -> Vec<Pixel> {
-        Self::draw_with_align(self.pos, &self.texture, self.align, self.modifier)
+        let pos = self
+            .align
+            .apply_to(self.pos, Self::content_size(&self.texture));
+
+        Self::draw_styled(pos, &self.texture, &self.spans, self.modifier)
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        Self::texture_area(self.pos, &self.texture, self.align)
     }
 }
@@ -1,9 +1,9 @@
 use crate::elements::{
-    view::{ColChar, Modifier, ViewElement},
+    view::{Area, ColChar, Modifier, ViewElement},
     Pixel, Vec2D,
 };
 
-use super::TextAlign;
+use super::{parse_markup, StyledSpan, TextAlign};
 
 /// Displays text at the given position
 #[non_exhaustive]
@@ -17,6 +17,8 @@ pub struct Text {
     pub align: TextAlign,
     /// A raw [`Modifier`], determining the appearance of the `Text`
     pub modifier: Modifier,
+    /// Ranges of characters in the content which should use a different [`Modifier`]. You can generate these from markup with [`Text::from_markup()`]
+    pub spans: Vec<StyledSpan>,
 }
 
 impl Text {
@@ -36,9 +38,28 @@ impl Text {
             content: String::from(content),
             align: TextAlign::Begin,
             modifier,
+            spans: vec![],
         }
     }
 
+    /// Create a new Text element from a string with style markup such as `[red]warning[/] now`. See [`parse_markup()`](super::parse_markup()) for the full syntax. Characters outside of any tag will use the given `modifier`
+    ///
+    /// # Errors
+    /// Returns an error if the markup could not be parsed, or if it contains a newline
+    pub fn from_markup(pos: Vec2D, markup: &str, modifier: Modifier) -> Result<Self, String> {
+        let (content, spans) = parse_markup(markup)?;
+        if content.contains('\n') {
+            return Err(String::from(
+                "Text can only contain a single line, for multi-line strings use Sprite::from_markup",
+            ));
+        }
+
+        Ok(Self {
+            spans,
+            ..Self::new(pos, &content, modifier)
+        })
+    }
+
     /// Return the `Text` with the modified align property
     #[must_use]
     pub const fn with_align(self, align: TextAlign) -> Self {
@@ -50,6 +71,28 @@ impl Text {
     /// Return a vector of Pixels to display the given content
     #[must_use]
     pub fn draw(pos: Vec2D, content: &str, modifier: Modifier) -> Vec<Pixel> {
+        Self::draw_styled(pos, content, &[], modifier)
+    }
+
+    /// Return a vector of Pixels to display the given content, with characters covered by the [`StyledSpan`]s using the span's [`Modifier`] instead of the default `modifier`
+    #[must_use]
+    pub fn draw_styled(
+        pos: Vec2D,
+        content: &str,
+        spans: &[StyledSpan],
+        modifier: Modifier,
+    ) -> Vec<Pixel> {
+        Self::draw_from_index(pos, content, 0, spans, modifier)
+    }
+
+    /// Same as [`Text::draw_styled()`], but with the first character of `content` treated as being at `start_index` for the purposes of the spans
+    pub(crate) fn draw_from_index(
+        pos: Vec2D,
+        content: &str,
+        start_index: usize,
+        spans: &[StyledSpan],
+        modifier: Modifier,
+    ) -> Vec<Pixel> {
         let mut pixels = vec![];
         for (x, text_char) in (0isize..).zip(content.chars()) {
             if text_char != ' ' {
@@ -57,7 +100,11 @@ impl Text {
                     pos + Vec2D::new(x, 0),
                     ColChar {
                         text_char,
-                        modifier,
+                        modifier: StyledSpan::modifier_at(
+                            spans,
+                            start_index + x as usize,
+                            modifier,
+                        ),
                     },
                 ));
             }
@@ -74,14 +121,39 @@ impl Text {
         align: TextAlign,
         modifier: Modifier,
     ) -> Vec<Pixel> {
-        let pos = Vec2D::new(align.apply_to(pos.x, content.len() as isize), pos.y);
+        let pos = Vec2D::new(
+            align.apply_to(pos.x, content.chars().count() as isize),
+            pos.y,
+        );
 
         Self::draw(pos, content, modifier)
     }
+
+    /// Return the position of the first character once the content has been aligned
+    fn aligned_pos(&self) -> Vec2D {
+        Vec2D::new(
+            self.align
+                .apply_to(self.pos.x, self.content.chars().count() as isize),
+            self.pos.y,
+        )
+    }
 }
 
 impl ViewElement for Text {
     fn active_pixels(&self) -> Vec<Pixel> {
-        Self::draw_with_align(self.pos, &self.content, self.align, self.modifier)
+        Self::draw_styled(
+            self.aligned_pos(),
+            &self.content,
+            &self.spans,
+            self.modifier,
+        )
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        let area = Area::new(
+            self.aligned_pos(),
+            Vec2D::new(self.content.chars().count() as isize, 1),
+        );
+        (!area.is_empty()).then_some(area)
     }
 }
@@ -0,0 +1,456 @@
+use super::{parse_markup, StyledSpan, TextAlign2D};
+use crate::elements::{
+    view::{ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+use std::{fmt, mem};
+
+/// A single wrapped line of text, with each character kept alongside its index in the original content
+// Kept `pub(crate)` so it can never leak into the public API, even though the module is private
+#[allow(clippy::redundant_pub_crate)]
+#[derive(Debug, Clone)]
+pub(crate) struct WrappedLine {
+    /// The characters of the line and their index in the original content
+    pub(crate) chars: Vec<(usize, char)>,
+    /// Whether this is the last line of its paragraph (i.e. followed by a `\n` or the end of the text)
+    pub(crate) ends_paragraph: bool,
+}
+
+impl fmt::Display for WrappedLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars.iter().try_for_each(|(_, c)| write!(f, "{c}"))
+    }
+}
+
+/// How a [`TextBox`] should break up lines that are too long to fit in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextWrap {
+    /// Break lines between words where possible. Words too long to fit on a line by themselves are broken between characters
+    #[default]
+    Word,
+    /// Break lines at exactly the width of the box, even if that is in the middle of a word
+    Character,
+    /// Do not wrap at all. Lines that are too long will be cut off (or ended with an ellipsis if [`Overflow::Ellipsis`] is used)
+    None,
+}
+
+impl TextWrap {
+    /// Split the text into lines no longer than the given width, as dictated by the `TextWrap` enum variation. Existing newlines are always kept
+    #[must_use]
+    pub fn wrap_lines(self, content: &str, width: usize) -> Vec<String> {
+        self.wrap_indexed(content, width)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Split the text into [`WrappedLine`]s no longer than the given width
+    pub(crate) fn wrap_indexed(self, content: &str, width: usize) -> Vec<WrappedLine> {
+        let width = width.max(1);
+        let mut lines = vec![];
+        let mut index = 0;
+
+        for paragraph in content.split('\n') {
+            let chars: Vec<(usize, char)> = (index..).zip(paragraph.chars()).collect();
+            index += chars.len() + 1;
+
+            let paragraph_lines = match self {
+                Self::None => vec![chars],
+                Self::Character if chars.is_empty() => vec![chars],
+                Self::Character => chars.chunks(width).map(<[_]>::to_vec).collect(),
+                Self::Word => wrap_words(&chars, width),
+            };
+
+            let last = paragraph_lines.len() - 1;
+            lines.extend(
+                paragraph_lines
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, chars)| WrappedLine {
+                        chars,
+                        ends_paragraph: i == last,
+                    }),
+            );
+        }
+
+        lines
+    }
+}
+
+/// Remove all trailing spaces from the line
+fn trim_line_end(line: &mut Vec<(usize, char)>) {
+    while line.last().is_some_and(|(_, c)| *c == ' ') {
+        line.pop();
+    }
+}
+
+/// Split the characters into runs of spaces and runs of everything else
+fn split_runs(chars: &[(usize, char)]) -> Vec<&[(usize, char)]> {
+    let mut runs = vec![];
+    let mut start = 0;
+    for i in 1..=chars.len() {
+        if i == chars.len() || (chars[i].1 == ' ') != (chars[i - 1].1 == ' ') {
+            runs.push(&chars[start..i]);
+            start = i;
+        }
+    }
+
+    runs
+}
+
+/// Greedily fill lines with words, dropping the spaces where lines are broken
+fn wrap_words(chars: &[(usize, char)], width: usize) -> Vec<Vec<(usize, char)>> {
+    let mut lines = vec![];
+    let mut line: Vec<(usize, char)> = vec![];
+
+    for token in split_runs(chars) {
+        if token[0].1 == ' ' {
+            if line.len() + token.len() <= width {
+                line.extend_from_slice(token);
+            } else if !line.is_empty() {
+                lines.push(mem::take(&mut line));
+            }
+            continue;
+        }
+
+        if line.len() + token.len() > width {
+            trim_line_end(&mut line);
+            if !line.is_empty() {
+                lines.push(mem::take(&mut line));
+            }
+        }
+
+        let mut word = token;
+        while word.len() > width {
+            lines.push(word[..width].to_vec());
+            word = &word[width..];
+        }
+        line.extend_from_slice(word);
+    }
+
+    trim_line_end(&mut line);
+    lines.push(line);
+
+    lines
+}
+
+/// What a [`TextBox`] should do with text that doesn't fit inside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Simply cut off any lines that don't fit
+    #[default]
+    Clip,
+    /// Cut off any lines that don't fit, and end the last visible line with a `…` to show that there is more text
+    Ellipsis,
+    /// Only show the lines starting from [`TextBox::scroll`]
+    Scroll,
+}
+
+/// A block of multi-line text with a maximum size. Unlike [`Text`](super::Text) and [`Sprite`](super::Sprite), the `TextBox` wraps its content to fit inside its width, and can align each line within the box
+/// ```
+/// use gemini_engine::elements::{ascii::{TextAlign, TextAlign2D, TextBox}, view::{Modifier, ViewElement}, Vec2D};
+///
+/// let text_box = TextBox::new(
+///     Vec2D::ZERO,
+///     Vec2D::new(12, 4),
+///     "The quick brown fox jumps over the lazy dog",
+///     Modifier::None,
+/// )
+/// .with_align(TextAlign2D::new(TextAlign::Centered, TextAlign::Begin));
+///
+/// assert_eq!(text_box.lines(), vec!["The quick", "brown fox", "jumps over", "the lazy dog"]);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct TextBox {
+    /// The position of the top-left corner of the `TextBox`
+    pub pos: Vec2D,
+    /// The size of the `TextBox`, including its [`padding`](TextBox::padding)
+    pub size: Vec2D,
+    /// The text content of the element. Newlines are respected
+    pub content: String,
+    /// A raw [`Modifier`], determining the appearance of the `TextBox`
+    pub modifier: Modifier,
+    /// How lines that are too long should be wrapped
+    pub wrap: TextWrap,
+    /// How each line should align horizontally within the box, and how the block of lines should align vertically
+    pub align: TextAlign2D,
+    /// If true, every line but the last line of each paragraph will be stretched to the full width of the box by widening the gaps between words. This overrides the horizontal [`align`](TextBox::align)
+    pub justify: bool,
+    /// What to do with lines that don't fit in the box
+    pub overflow: Overflow,
+    /// The index of the first visible line when using [`Overflow::Scroll`]
+    pub scroll: usize,
+    /// The empty space kept between the edges of the box and the text, on the X and Y axes
+    pub padding: Vec2D,
+    /// Ranges of characters in the content which should use a different [`Modifier`]. You can generate these from markup with [`TextBox::from_markup()`]
+    pub spans: Vec<StyledSpan>,
+}
+
+impl TextBox {
+    /// Create a new `TextBox` with word wrapping, top-left alignment and no padding
+    #[must_use]
+    pub fn new(pos: Vec2D, size: Vec2D, content: &str, modifier: Modifier) -> Self {
+        Self {
+            pos,
+            size,
+            content: String::from(content),
+            modifier,
+            wrap: TextWrap::default(),
+            align: TextAlign2D::default(),
+            justify: false,
+            overflow: Overflow::default(),
+            scroll: 0,
+            padding: Vec2D::ZERO,
+            spans: vec![],
+        }
+    }
+
+    /// Create a new `TextBox` from a string with style markup such as `[red]warning[/] now`. See [`parse_markup()`](super::parse_markup()) for the full syntax. Characters outside of any tag will use the given `modifier`
+    ///
+    /// # Errors
+    /// Returns an error if the markup could not be parsed
+    pub fn from_markup(
+        pos: Vec2D,
+        size: Vec2D,
+        markup: &str,
+        modifier: Modifier,
+    ) -> Result<Self, String> {
+        let (content, spans) = parse_markup(markup)?;
+
+        Ok(Self {
+            spans,
+            ..Self::new(pos, size, &content, modifier)
+        })
+    }
+
+    /// Return the `TextBox` with the modified wrap property
+    #[must_use]
+    pub const fn with_wrap(mut self, wrap: TextWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Return the `TextBox` with the modified align property
+    #[must_use]
+    pub const fn with_align(mut self, align: TextAlign2D) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Return the `TextBox` with the modified justify property
+    #[must_use]
+    pub const fn with_justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Return the `TextBox` with the modified overflow property
+    #[must_use]
+    pub const fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Return the `TextBox` with the modified padding property
+    #[must_use]
+    pub const fn with_padding(mut self, padding: Vec2D) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// The space available for text inside the box, after the padding has been removed
+    #[must_use]
+    pub fn inner_size(&self) -> Vec2D {
+        let inner = self.size - self.padding * 2;
+        Vec2D::new(inner.x.max(0), inner.y.max(0))
+    }
+
+    /// Return all the lines of the content after wrapping, including those that don't fit in the box
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        self.wrap
+            .wrap_lines(&self.content, self.inner_size().x as usize)
+    }
+
+    /// The highest value [`scroll`](TextBox::scroll) can have while still filling the box with text
+    #[must_use]
+    pub fn max_scroll(&self) -> usize {
+        self.lines()
+            .len()
+            .saturating_sub(self.inner_size().y as usize)
+    }
+
+    /// Scroll the content by the given number of lines, clamped so as not to scroll past the beginning or end of the text
+    pub fn scroll_by(&mut self, lines: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(lines)
+            .min(self.max_scroll());
+    }
+
+    /// Return the lines that fit in the box, with the overflow handling applied
+    fn visible_lines(&self) -> Vec<WrappedLine> {
+        let inner_size = self.inner_size();
+        let (width, height) = (inner_size.x as usize, inner_size.y as usize);
+        let mut lines = self.wrap.wrap_indexed(&self.content, width);
+
+        let overflowing = lines.len() > height;
+        if self.overflow == Overflow::Scroll {
+            let start = self.scroll.min(lines.len().saturating_sub(height));
+            lines.drain(..start);
+        }
+        lines.truncate(height);
+
+        for (i, line) in lines.iter_mut().enumerate() {
+            let is_last_visible = i + 1 == height;
+            let cut_off = line.chars.len() > width
+                || (overflowing && is_last_visible && self.overflow == Overflow::Ellipsis);
+
+            if self.overflow == Overflow::Ellipsis && cut_off && width > 0 {
+                // The ellipsis takes the style of the last character before it
+                let first_index = line.chars.first().map_or(0, |(index, _)| *index);
+                line.chars.truncate(width - 1);
+                trim_line_end(&mut line.chars);
+                let index = line.chars.last().map_or(first_index, |(index, _)| *index);
+                line.chars.push((index, '…'));
+            } else {
+                line.chars.truncate(width);
+            }
+        }
+
+        lines
+    }
+
+    /// Return the position of every visible character in the box, along with its index in [`content`](TextBox::content)
+    pub(crate) fn layout(&self) -> Vec<(Vec2D, usize, char)> {
+        let inner_size = self.inner_size();
+        let lines = self.visible_lines();
+        let origin = self.pos
+            + self.padding
+            + Vec2D::new(
+                0,
+                self.align
+                    .y
+                    .offset_within(inner_size.y, lines.len() as isize),
+            );
+
+        let mut layout = vec![];
+        for (y, line) in (0..).zip(&lines) {
+            let line_length = line.chars.len() as isize;
+            let justify = self.justify && !line.ends_paragraph && self.wrap != TextWrap::None;
+
+            let gaps = if justify {
+                (1..line.chars.len())
+                    .filter(|i| line.chars[*i].1 == ' ' && line.chars[i - 1].1 != ' ')
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            let mut x = if gaps.is_empty() {
+                self.align.x.offset_within(inner_size.x, line_length)
+            } else {
+                0
+            };
+            let extra_space = (inner_size.x - line_length).max(0) as usize;
+
+            for (i, (index, text_char)) in line.chars.iter().enumerate() {
+                if let Some(gap) = gaps.iter().position(|g| *g == i) {
+                    let widened =
+                        extra_space / gaps.len() + usize::from(gap < extra_space % gaps.len());
+                    x += widened as isize;
+                }
+                layout.push((origin + Vec2D::new(x, y), *index, *text_char));
+                x += 1;
+            }
+        }
+
+        layout
+    }
+}
+
+impl ViewElement for TextBox {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.layout()
+            .into_iter()
+            .filter(|(_, _, text_char)| *text_char != ' ')
+            .map(|(pos, index, text_char)| {
+                let modifier = StyledSpan::modifier_at(&self.spans, index, self.modifier);
+                Pixel::new(pos, ColChar::new(text_char, modifier))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::ascii::TextAlign;
+    use crate::elements::view::utils;
+
+    fn render_rows(text_box: &TextBox) -> Vec<String> {
+        utils::render_rows(&text_box.active_pixels(), text_box.size, ' ')
+    }
+
+    #[test]
+    fn word_wrap_breaks_long_words() {
+        assert_eq!(
+            TextWrap::Word.wrap_lines("a verylongword b", 5),
+            vec!["a", "veryl", "ongwo", "rd b"]
+        );
+    }
+
+    #[test]
+    fn character_wrap_keeps_newlines() {
+        assert_eq!(
+            TextWrap::Character.wrap_lines("abcdef\n\nxy", 4),
+            vec!["abcd", "ef", "", "xy"]
+        );
+    }
+
+    #[test]
+    fn justify_and_align() {
+        let text_box = TextBox::new(Vec2D::ZERO, Vec2D::new(9, 3), "aa bb cc dd", Modifier::None)
+            .with_justify(true)
+            .with_align(TextAlign2D::new(TextAlign::End, TextAlign::End));
+
+        assert_eq!(
+            render_rows(&text_box),
+            vec!["         ", "aa  bb cc", "       dd"]
+        );
+    }
+
+    #[test]
+    fn ellipsis_and_scroll() {
+        let mut text_box = TextBox::new(
+            Vec2D::ZERO,
+            Vec2D::new(6, 2),
+            "one two three four",
+            Modifier::None,
+        )
+        .with_overflow(Overflow::Ellipsis);
+        assert_eq!(render_rows(&text_box), vec!["one   ", "two…  "]);
+
+        // The ellipsis is styled like the character before it, not the start of the line
+        let styled = TextBox::from_markup(
+            Vec2D::ZERO,
+            Vec2D::new(10, 1),
+            "[red]ab[/] cd efghijkl",
+            Modifier::None,
+        )
+        .expect("Markup is valid")
+        .with_overflow(Overflow::Ellipsis);
+        let ellipsis = styled
+            .active_pixels()
+            .into_iter()
+            .find(|pixel| pixel.fill_char.text_char == '…')
+            .expect("Line is cut off");
+        assert_eq!(ellipsis.fill_char.modifier, Modifier::None);
+
+        text_box.overflow = Overflow::Scroll;
+        text_box.scroll_by(5);
+        assert_eq!(text_box.scroll, 2);
+        assert_eq!(render_rows(&text_box), vec!["three ", "four  "]);
+    }
+}
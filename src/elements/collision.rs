@@ -0,0 +1,7 @@
+//! This module holds tools for finding out where elements collide. For a handful of elements a [`CollisionContainer`](super::containers::CollisionContainer) is enough, but a [`CollisionWorld`] stays fast with hundreds of colliders, and [`CollisionMask`]s give pixel-perfect collisions between sprites
+
+mod mask;
+pub use mask::CollisionMask;
+
+mod world;
+pub use world::{ColliderId, Collision, CollisionWorld};
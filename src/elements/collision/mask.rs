@@ -0,0 +1,224 @@
+use crate::elements::{
+    ascii::{remove_leading_newlines, AnimatedSprite, Sprite, TextureTransform},
+    view::{Area, Vec2D},
+};
+use std::collections::HashSet;
+
+/// The cells of a texture that count for collisions, relative to the texture's top-left corner. Masks are usually made once from a [`Sprite`]'s texture and then tested at whatever position the sprite is at, first comparing bounding boxes and only then the cells themselves
+/// ```
+/// use gemini_engine::elements::{collision::CollisionMask, Vec2D};
+///
+/// let ball = CollisionMask::from_texture("
+///  ()
+/// (  )
+///  ()");
+/// let spike = CollisionMask::from_texture("/\\");
+///
+/// // The spike reaches into the gap in the middle of the ball, but doesn't touch it
+/// assert!(!ball.collides(Vec2D::new(0, 0), &spike, Vec2D::new(1, 1)));
+/// assert_eq!(
+///     ball.overlap(Vec2D::new(0, 0), &spike, Vec2D::new(2, 1)),
+///     vec![Vec2D::new(3, 1)],
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CollisionMask {
+    cells: HashSet<Vec2D>,
+    size: Vec2D,
+}
+
+impl CollisionMask {
+    /// Create a mask from a texture, where every character that isn't a space collides. All newlines at the beginning of the texture will be removed, the same as with a [`Sprite`]
+    #[must_use]
+    pub fn from_texture(texture: &str) -> Self {
+        Self::from_texture_where(texture, |c| c != ' ')
+    }
+
+    /// Create a mask from a separate mask layer drawn over the texture, where only the `solid` character collides. This lets parts of a sprite, like a character's hair or a ghost's tail, be drawn without colliding
+    /// ```
+    /// use gemini_engine::elements::{collision::CollisionMask, Vec2D};
+    ///
+    /// let texture = "
+    ///  ~~
+    /// (oo)";
+    /// let mask = CollisionMask::from_layer("
+    ///  ..
+    /// XXXX", 'X');
+    ///
+    /// assert_eq!(mask.size(), Vec2D::new(4, 2));
+    /// assert!(!mask.contains(Vec2D::new(1, 0)));
+    /// assert!(mask.contains(Vec2D::new(1, 1)));
+    /// ```
+    #[must_use]
+    pub fn from_layer(mask: &str, solid: char) -> Self {
+        Self::from_texture_where(mask, |c| c == solid)
+    }
+
+    /// Create a mask from a texture, where the characters that collide are chosen by a function
+    #[must_use]
+    pub fn from_texture_where(texture: &str, is_solid: impl Fn(char) -> bool) -> Self {
+        Self::from_lines(&remove_leading_newlines(texture), is_solid)
+    }
+
+    /// Create a mask from a texture without removing its leading newlines, so that it lines up with a texture that has already been processed
+    fn from_lines(texture: &str, is_solid: impl Fn(char) -> bool) -> Self {
+        let mut cells = HashSet::new();
+        let mut size = Vec2D::ZERO;
+        for (y, line) in (0isize..).zip(texture.split('\n')) {
+            for (x, c) in (0isize..).zip(line.chars()) {
+                if is_solid(c) {
+                    cells.insert(Vec2D::new(x, y));
+                }
+                size.x = size.x.max(x + 1);
+            }
+            size.y = y + 1;
+        }
+
+        Self { cells, size }
+    }
+
+    /// Create a mask from the `Sprite`'s current texture, where every character that isn't a space collides. If the sprite is aligned, test the mask at the position of [`Sprite::texture_area()`] rather than the sprite's own position. Since transforming a sprite changes its texture, create a new mask afterwards or transform the mask the same way with [`CollisionMask::transform()`]
+    #[must_use]
+    pub fn from_sprite(sprite: &Sprite) -> Self {
+        Self::from_lines(&sprite.texture, |c| c != ' ')
+    }
+
+    /// Create a mask for each of the `AnimatedSprite`'s frames, which can be indexed with its [`current_frame`](AnimatedSprite::current_frame)
+    #[must_use]
+    pub fn from_frames(sprite: &AnimatedSprite) -> Vec<Self> {
+        sprite
+            .frames
+            .iter()
+            .map(|frame| Self::from_lines(frame, |c| c != ' '))
+            .collect()
+    }
+
+    /// Return the width and height of the texture the mask was made from
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        self.size
+    }
+
+    /// Return the number of cells that collide
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if no cells collide
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns true if the cell at the position, relative to the top-left corner of the mask, collides
+    #[must_use]
+    pub fn contains(&self, pos: Vec2D) -> bool {
+        self.cells.contains(&pos)
+    }
+
+    /// Return the cells that collide when the top-left corner of the mask is at `pos`, sorted by row. These can be added to a [`CollisionWorld`](super::CollisionWorld) with [`insert_points()`](super::CollisionWorld::insert_points())
+    #[must_use]
+    pub fn points(&self, pos: Vec2D) -> Vec<Vec2D> {
+        let mut points: Vec<Vec2D> = self.cells.iter().map(|p| *p + pos).collect();
+        points.sort_unstable_by_key(|p| (p.y, p.x));
+        points
+    }
+
+    /// Return the smallest [`Area`] containing every cell that collides when the top-left corner of the mask is at `pos`, or `None` if no cells collide
+    #[must_use]
+    pub fn bounding_box(&self, pos: Vec2D) -> Option<Area> {
+        Area::from_points(self.cells.iter().map(|p| *p + pos))
+    }
+
+    /// Flip or rotate the mask, moving its cells the same way [`Sprite::transform()`] moves the characters of a texture
+    pub fn transform(&mut self, transform: TextureTransform) {
+        let Vec2D {
+            x: width,
+            y: height,
+        } = self.size;
+        self.cells = self
+            .cells
+            .iter()
+            .map(|p| match transform {
+                TextureTransform::FlipHorizontal => Vec2D::new(width - 1 - p.x, p.y),
+                TextureTransform::FlipVertical => Vec2D::new(p.x, height - 1 - p.y),
+                TextureTransform::RotateClockwise => Vec2D::new(height - 1 - p.y, p.x),
+                TextureTransform::RotateAnticlockwise => Vec2D::new(p.y, width - 1 - p.x),
+            })
+            .collect();
+
+        if matches!(
+            transform,
+            TextureTransform::RotateClockwise | TextureTransform::RotateAnticlockwise
+        ) {
+            self.size = Vec2D::new(height, width);
+        }
+    }
+
+    /// Return the cells where this mask, with its top-left corner at `pos`, overlaps the other mask, with its top-left corner at `other_pos`. The cells are sorted by row. The bounding boxes of the masks are compared first, so masks that are far apart are quick to check
+    #[must_use]
+    pub fn overlap(&self, pos: Vec2D, other: &Self, other_pos: Vec2D) -> Vec<Vec2D> {
+        let (Some(own_box), Some(other_box)) =
+            (self.bounding_box(pos), other.bounding_box(other_pos))
+        else {
+            return vec![];
+        };
+        let Some(shared) = own_box.intersection(&other_box) else {
+            return vec![];
+        };
+
+        // Go through whichever mask has fewer cells
+        let (smaller, smaller_pos, larger, larger_pos) = if self.len() <= other.len() {
+            (self, pos, other, other_pos)
+        } else {
+            (other, other_pos, self, pos)
+        };
+        let mut cells: Vec<Vec2D> = smaller
+            .cells
+            .iter()
+            .map(|p| *p + smaller_pos)
+            .filter(|p| shared.contains(*p) && larger.contains(*p - larger_pos))
+            .collect();
+        cells.sort_unstable_by_key(|p| (p.y, p.x));
+
+        cells
+    }
+
+    /// Returns true if this mask, with its top-left corner at `pos`, overlaps the other mask, with its top-left corner at `other_pos`
+    #[must_use]
+    pub fn collides(&self, pos: Vec2D, other: &Self, other_pos: Vec2D) -> bool {
+        !self.overlap(pos, other, other_pos).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::view::Modifier;
+
+    #[test]
+    fn transformed_masks_match_transformed_sprites() {
+        let sprite = Sprite::new(Vec2D::ZERO, "#  \n## \n ###\n", Modifier::None);
+        let mask = CollisionMask::from_sprite(&sprite);
+        assert_eq!(mask.size(), Vec2D::new(4, 4));
+
+        for transform in [
+            TextureTransform::FlipHorizontal,
+            TextureTransform::FlipVertical,
+            TextureTransform::RotateClockwise,
+            TextureTransform::RotateAnticlockwise,
+        ] {
+            let mut transformed = mask.clone();
+            transformed.transform(transform);
+            let mut transformed_sprite = sprite.clone();
+            transformed_sprite.transform(transform);
+            let expected = CollisionMask::from_sprite(&transformed_sprite);
+            assert_eq!(
+                transformed.points(Vec2D::ZERO),
+                expected.points(Vec2D::ZERO),
+                "{transform:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use crate::elements::view::{Area, Vec2D, ViewElement};
+use std::collections::{HashMap, HashSet};
+
+/// Identifies a collider in a [`CollisionWorld`]. Ids are never reused, even after the collider is removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColliderId(usize);
+
+/// A collider that was hit, and the cells where it was hit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    /// The collider that was hit
+    pub id: ColliderId,
+    /// The cells where the collider was hit, sorted by row
+    pub cells: Vec<Vec2D>,
+}
+
+/// The cells of a collider, stored relative to its offset so that moving it doesn't mean rebuilding them
+#[derive(Debug, Clone)]
+struct Collider {
+    cells: HashSet<Vec2D>,
+    local_bounds: Area,
+    offset: Vec2D,
+}
+
+impl Collider {
+    fn new(cells: impl IntoIterator<Item = Vec2D>) -> Self {
+        let cells: HashSet<Vec2D> = cells.into_iter().collect();
+        let local_bounds = Area::from_points(cells.iter().copied()).unwrap_or_default();
+
+        Self {
+            cells,
+            local_bounds,
+            offset: Vec2D::ZERO,
+        }
+    }
+
+    fn bounds(&self) -> Area {
+        Area::new(self.local_bounds.pos + self.offset, self.local_bounds.size)
+    }
+
+    fn contains(&self, pos: Vec2D) -> bool {
+        self.cells.contains(&(pos - self.offset))
+    }
+}
+
+/// A collection of colliders indexed by a spatial hash, for finding what collides with what without checking every cell of every collider. Each collider is only checked cell by cell if its bounding box overlaps the cells being tested, and moving a collider with [`CollisionWorld::translate()`] only updates the spatial hash
+/// ```
+/// use gemini_engine::elements::{collision::CollisionWorld, geometry::Rect, view::ColChar, Vec2D};
+///
+/// let mut world = CollisionWorld::new();
+/// let wall = world.insert(&Rect::new(Vec2D::new(10, 0), Vec2D::new(2, 10), ColChar::SOLID));
+/// let player = world.insert(&Rect::new(Vec2D::new(5, 4), Vec2D::new(2, 2), ColChar::SOLID));
+///
+/// // Check whether the player can move 4 cells to the right
+/// let hits = world.collisions_of(player, Vec2D::new(4, 0));
+/// assert_eq!(hits.len(), 1);
+/// assert_eq!(hits[0].id, wall);
+/// assert_eq!(hits[0].cells, vec![Vec2D::new(10, 4), Vec2D::new(10, 5)]);
+///
+/// world.translate(player, Vec2D::new(3, 0)).unwrap();
+/// assert!(world.pairs().is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CollisionWorld {
+    bucket_size: isize,
+    colliders: HashMap<ColliderId, Collider>,
+    buckets: HashMap<Vec2D, Vec<ColliderId>>,
+    next_id: usize,
+}
+
+impl Default for CollisionWorld {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CollisionWorld {
+    /// The default width and height of each bucket in the spatial hash
+    pub const DEFAULT_BUCKET_SIZE: isize = 8;
+
+    /// Create a new, empty `CollisionWorld`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_bucket_size(Self::DEFAULT_BUCKET_SIZE)
+    }
+
+    /// Create a new, empty `CollisionWorld` whose spatial hash is split into buckets of the given width and height. Buckets around the size of a typical collider work best
+    #[must_use]
+    pub fn with_bucket_size(bucket_size: isize) -> Self {
+        Self {
+            bucket_size: bucket_size.max(1),
+            colliders: HashMap::new(),
+            buckets: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Return the number of colliders in the `CollisionWorld`
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.colliders.len()
+    }
+
+    /// Returns true if the `CollisionWorld` has no colliders
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.colliders.is_empty()
+    }
+
+    /// Return the buckets covered by the area
+    fn buckets_in(&self, area: Area) -> impl Iterator<Item = Vec2D> {
+        let (start, end) = if area.is_empty() {
+            (Vec2D::ZERO, Vec2D::ZERO)
+        } else {
+            let end = area.end() - Vec2D::new(1, 1);
+            (
+                Vec2D::new(
+                    area.pos.x.div_euclid(self.bucket_size),
+                    area.pos.y.div_euclid(self.bucket_size),
+                ),
+                Vec2D::new(
+                    end.x.div_euclid(self.bucket_size) + 1,
+                    end.y.div_euclid(self.bucket_size) + 1,
+                ),
+            )
+        };
+
+        (start.y..end.y).flat_map(move |y| (start.x..end.x).map(move |x| Vec2D::new(x, y)))
+    }
+
+    fn add_to_buckets(&mut self, id: ColliderId, area: Area) {
+        for bucket in self.buckets_in(area) {
+            self.buckets.entry(bucket).or_default().push(id);
+        }
+    }
+
+    fn remove_from_bucket(&mut self, id: ColliderId, bucket: Vec2D) {
+        if let Some(ids) = self.buckets.get_mut(&bucket) {
+            ids.retain(|other| *other != id);
+            if ids.is_empty() {
+                self.buckets.remove(&bucket);
+            }
+        }
+    }
+
+    fn remove_from_buckets(&mut self, id: ColliderId, area: Area) {
+        for bucket in self.buckets_in(area) {
+            self.remove_from_bucket(id, bucket);
+        }
+    }
+
+    /// Add a collider covering every cell in `cells`, returning its [`ColliderId`]
+    pub fn insert_points(&mut self, cells: impl IntoIterator<Item = Vec2D>) -> ColliderId {
+        let id = ColliderId(self.next_id);
+        self.next_id += 1;
+
+        let collider = Collider::new(cells);
+        self.add_to_buckets(id, collider.bounds());
+        self.colliders.insert(id, collider);
+
+        id
+    }
+
+    /// Add a collider covering every one of the element's [`active_points()`](ViewElement::active_points()), returning its [`ColliderId`]. The collider is a snapshot, so use [`CollisionWorld::update()`] or [`CollisionWorld::translate()`] when the element changes
+    pub fn insert(&mut self, element: &impl ViewElement) -> ColliderId {
+        self.insert_points(element.active_points())
+    }
+
+    /// Remove the collider, returning true if it was in the `CollisionWorld`
+    pub fn remove(&mut self, id: ColliderId) -> bool {
+        let Some(collider) = self.colliders.remove(&id) else {
+            return false;
+        };
+        self.remove_from_buckets(id, collider.bounds());
+
+        true
+    }
+
+    /// Replace the cells covered by the collider
+    ///
+    /// # Errors
+    /// Returns an error if there is no collider with the id
+    pub fn update_points(
+        &mut self,
+        id: ColliderId,
+        cells: impl IntoIterator<Item = Vec2D>,
+    ) -> Result<(), String> {
+        let old_bounds = self.bounds(id).ok_or_else(|| missing_collider(id))?;
+        self.remove_from_buckets(id, old_bounds);
+
+        let collider = Collider::new(cells);
+        self.add_to_buckets(id, collider.bounds());
+        self.colliders.insert(id, collider);
+
+        Ok(())
+    }
+
+    /// Replace the cells covered by the collider with the element's [`active_points()`](ViewElement::active_points())
+    ///
+    /// # Errors
+    /// Returns an error if there is no collider with the id
+    pub fn update(&mut self, id: ColliderId, element: &impl ViewElement) -> Result<(), String> {
+        self.update_points(id, element.active_points())
+    }
+
+    /// Move the collider by the offset. This only touches the spatial hash buckets the collider enters or leaves, so it's much faster than [`CollisionWorld::update()`] for elements that only move
+    ///
+    /// # Errors
+    /// Returns an error if there is no collider with the id
+    pub fn translate(&mut self, id: ColliderId, offset: Vec2D) -> Result<(), String> {
+        let collider = self
+            .colliders
+            .get(&id)
+            .ok_or_else(|| missing_collider(id))?;
+        let old_bounds = collider.bounds();
+        let new_bounds = Area::new(old_bounds.pos + offset, old_bounds.size);
+
+        let old_buckets: HashSet<Vec2D> = self.buckets_in(old_bounds).collect();
+        let new_buckets: HashSet<Vec2D> = self.buckets_in(new_bounds).collect();
+        for bucket in old_buckets.difference(&new_buckets) {
+            self.remove_from_bucket(id, *bucket);
+        }
+        for bucket in new_buckets.difference(&old_buckets) {
+            self.buckets.entry(*bucket).or_default().push(id);
+        }
+
+        if let Some(collider) = self.colliders.get_mut(&id) {
+            collider.offset += offset;
+        }
+
+        Ok(())
+    }
+
+    /// Return the bounding box of the collider, or `None` if there is no collider with the id
+    #[must_use]
+    pub fn bounds(&self, id: ColliderId) -> Option<Area> {
+        self.colliders.get(&id).map(Collider::bounds)
+    }
+
+    /// Return the ids of every collider whose bounding box overlaps the area, without checking their cells. This is the broad phase used by every other query
+    #[must_use]
+    pub fn candidates(&self, area: Area) -> Vec<ColliderId> {
+        let mut ids: Vec<ColliderId> = self
+            .buckets_in(area)
+            .filter_map(|bucket| self.buckets.get(&bucket))
+            .flatten()
+            .copied()
+            .filter(|id| self.colliders[id].bounds().overlaps(&area))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids
+    }
+
+    /// Return the ids of every collider covering the position
+    #[must_use]
+    pub fn colliders_at(&self, pos: Vec2D) -> Vec<ColliderId> {
+        self.candidates(Area::new(pos, Vec2D::new(1, 1)))
+            .into_iter()
+            .filter(|id| self.colliders[id].contains(pos))
+            .collect()
+    }
+
+    /// Returns true if any collider covers the position
+    #[must_use]
+    pub fn contains(&self, pos: Vec2D) -> bool {
+        !self.colliders_at(pos).is_empty()
+    }
+
+    /// Return every collider that overlaps the cells, and where. The collider with the `ignore` id is skipped, which is useful for checking a collider against everything else
+    #[must_use]
+    pub fn query_points(&self, cells: &[Vec2D], ignore: Option<ColliderId>) -> Vec<Collision> {
+        let Some(area) = Area::from_points(cells.iter().copied()) else {
+            return vec![];
+        };
+
+        self.candidates(area)
+            .into_iter()
+            .filter(|id| Some(*id) != ignore)
+            .filter_map(|id| {
+                let collider = &self.colliders[&id];
+                let mut hit: Vec<Vec2D> = cells
+                    .iter()
+                    .copied()
+                    .filter(|cell| collider.contains(*cell))
+                    .collect();
+                if hit.is_empty() {
+                    return None;
+                }
+                hit.sort_unstable_by_key(|p| (p.y, p.x));
+                hit.dedup();
+                Some(Collision { id, cells: hit })
+            })
+            .collect()
+    }
+
+    /// Return every collider that the element would overlap if it was moved by the offset, and where
+    #[must_use]
+    pub fn query_element(&self, element: &impl ViewElement, offset: Vec2D) -> Vec<Collision> {
+        let cells: Vec<Vec2D> = element
+            .active_points()
+            .into_iter()
+            .map(|p| p + offset)
+            .collect();
+        self.query_points(&cells, None)
+    }
+
+    /// Return every other collider that the collider would overlap if it was moved by the offset, and where. Pass [`Vec2D::ZERO`] to check where it is now. Returns an empty list if there is no collider with the id
+    #[must_use]
+    pub fn collisions_of(&self, id: ColliderId, offset: Vec2D) -> Vec<Collision> {
+        let Some(collider) = self.colliders.get(&id) else {
+            return vec![];
+        };
+        let cells: Vec<Vec2D> = collider
+            .cells
+            .iter()
+            .map(|p| *p + collider.offset + offset)
+            .collect();
+
+        self.query_points(&cells, Some(id))
+    }
+
+    /// Return every pair of colliders that currently overlap. The first id of each pair is always the lower one, and the [`Collision`] holds the other collider and the cells they share
+    #[must_use]
+    pub fn pairs(&self) -> Vec<(ColliderId, Collision)> {
+        let mut checked = HashSet::new();
+        let mut pairs = vec![];
+
+        for ids in self.buckets.values() {
+            for (i, a) in ids.iter().enumerate() {
+                for b in &ids[i + 1..] {
+                    let (a, b) = if a < b { (*a, *b) } else { (*b, *a) };
+                    if !checked.insert((a, b)) {
+                        continue;
+                    }
+
+                    let (first, second) = (&self.colliders[&a], &self.colliders[&b]);
+                    if !first.bounds().overlaps(&second.bounds()) {
+                        continue;
+                    }
+                    // Go through whichever collider has fewer cells
+                    let (smaller, larger) = if first.cells.len() <= second.cells.len() {
+                        (first, second)
+                    } else {
+                        (second, first)
+                    };
+                    let mut cells: Vec<Vec2D> = smaller
+                        .cells
+                        .iter()
+                        .map(|p| *p + smaller.offset)
+                        .filter(|p| larger.contains(*p))
+                        .collect();
+                    if !cells.is_empty() {
+                        cells.sort_unstable_by_key(|p| (p.y, p.x));
+                        pairs.push((a, Collision { id: b, cells }));
+                    }
+                }
+            }
+        }
+
+        pairs.sort_unstable_by_key(|(a, collision)| (*a, collision.id));
+        pairs
+    }
+}
+
+fn missing_collider(id: ColliderId) -> String {
+    format!("No collider with id {id:?} in the CollisionWorld")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{geometry::Rect, view::ColChar};
+
+    #[test]
+    fn moving_colliders_between_buckets() {
+        let mut world = CollisionWorld::with_bucket_size(4);
+        let a = world.insert(&Rect::new(Vec2D::ZERO, Vec2D::new(3, 3), ColChar::SOLID));
+        let b = world.insert_points([Vec2D::new(20, 1), Vec2D::new(21, 1)]);
+        assert!(world.pairs().is_empty());
+        assert_eq!(world.colliders_at(Vec2D::new(21, 1)), vec![b]);
+
+        // Move far enough to change buckets, landing on the first collider
+        world
+            .translate(b, Vec2D::new(-19, 0))
+            .expect("collider should exist");
+        assert_eq!(world.colliders_at(Vec2D::new(21, 1)), vec![]);
+        let pairs = world.pairs();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].0, a);
+        assert_eq!(pairs[0].1.cells, vec![Vec2D::new(1, 1), Vec2D::new(2, 1)]);
+
+        assert!(world.remove(a));
+        assert!(!world.remove(a));
+        assert!(world.translate(a, Vec2D::ZERO).is_err());
+        assert!(world.collisions_of(b, Vec2D::ZERO).is_empty());
+        assert_eq!(world.len(), 1);
+    }
+}
@@ -11,3 +11,6 @@ pub use shader::CanShade;
 
 mod collision_container;
 pub use collision_container::CollisionContainer;
+
+mod clip_container;
+pub use clip_container::ClipContainer;
@@ -0,0 +1,69 @@
+use crate::elements::{
+    view::{Area, ViewElement},
+    Pixel, Vec2D,
+};
+
+/// `ClipContainer` is a container for a [`ViewElement`] that only lets through the pixels that fall inside its [`Area`], letting you treat part of the [`View`](crate::elements::View) as a smaller sub-view. If [`relative`](ClipContainer::relative) is true, the element is also moved so that its `Vec2D(0,0)` lines up with the top-left corner of the area
+#[derive(Debug, Clone)]
+pub struct ClipContainer<E: ViewElement> {
+    /// The element held by the `ClipContainer`. Must implement [`ViewElement`]
+    pub element: E,
+    /// The area outside of which pixels are cut off
+    pub area: Area,
+    /// Whether the element's positions are relative to the top-left corner of the area
+    pub relative: bool,
+}
+
+impl<E: ViewElement> ClipContainer<E> {
+    /// Create a new `ClipContainer` with [`relative`](ClipContainer::relative) set to false
+    pub const fn new(element: E, area: Area) -> Self {
+        Self {
+            element,
+            area,
+            relative: false,
+        }
+    }
+
+    /// Return the `ClipContainer` with its [`relative`](ClipContainer::relative) field set to the chosen value
+    #[must_use]
+    pub const fn with_relative(mut self, relative: bool) -> Self {
+        self.relative = relative;
+        self
+    }
+
+    const fn offset(&self) -> Vec2D {
+        if self.relative {
+            self.area.pos
+        } else {
+            Vec2D::ZERO
+        }
+    }
+}
+
+impl<E: ViewElement> ViewElement for ClipContainer<E> {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let offset = self.offset();
+        self.element
+            .active_pixels()
+            .into_iter()
+            .map(|pixel| Pixel::new(pixel.pos + offset, pixel.fill_char))
+            .filter(|pixel| self.area.contains(pixel.pos))
+            .collect()
+    }
+
+    fn active_points(&self) -> Vec<Vec2D> {
+        let offset = self.offset();
+        self.element
+            .active_points()
+            .into_iter()
+            .map(|pos| pos + offset)
+            .filter(|pos| self.area.contains(*pos))
+            .collect()
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        let element_box = self.element.bounding_box()?;
+        let offset_box = Area::new(element_box.pos + self.offset(), element_box.size);
+        self.area.intersection(&offset_box)
+    }
+}
@@ -1,13 +1,17 @@
-use crate::elements::{view::ViewElement, Pixel, Vec2D};
+use crate::elements::{
+    view::{Area, ViewElement},
+    Pixel, Vec2D,
+};
+use std::collections::HashSet;
 
-/// Contains references to all added objects. Meant to be used specifically for collision calculations
+/// Contains references to all added objects. Meant to be used specifically for collision calculations. Every check goes through the active points of every element, so for lots of elements or frequent checks consider a [`CollisionWorld`](crate::elements::collision::CollisionWorld) instead
 #[derive(Clone)]
 pub struct CollisionContainer<'a> {
     /// The elements used to define the collision hitbox. This can be anything that implements [`ViewElement`]
     pub elements: Vec<&'a dyn ViewElement>,
 }
 
-impl<'a> Default for CollisionContainer<'a> {
+impl Default for CollisionContainer<'_> {
     fn default() -> Self {
         Self::new()
     }
@@ -35,9 +39,9 @@ impl<'a> CollisionContainer<'a> {
     /// Returns true if there is an element from the `CollisionContainer` at the given coordinates
     #[must_use]
     pub fn contains(&self, pos: Vec2D) -> bool {
-        let collision_points = self.active_points();
-
-        collision_points.contains(&pos)
+        self.elements
+            .iter()
+            .any(|e| e.active_points().contains(&pos))
     }
 
     /// Returns true if the given [`ViewElement`] is overlapping the `CollisionContainer`
@@ -47,15 +51,21 @@ impl<'a> CollisionContainer<'a> {
 
     /// Returns true if the element will be overlapping the `CollisionContainer` when the offset is applied
     pub fn will_overlap_element(&self, element: &impl ViewElement, offset: Vec2D) -> bool {
-        let collision_points = self.active_points();
-
-        for element_point in element.active_points() {
-            if collision_points.contains(&(element_point + offset)) {
-                return true;
-            }
+        // Skip checking every point if the bounding boxes don't overlap
+        let (Some(own_box), Some(element_box)) = (self.bounding_box(), element.bounding_box())
+        else {
+            return false;
+        };
+        if !own_box.overlaps(&Area::new(element_box.pos + offset, element_box.size)) {
+            return false;
         }
 
-        false
+        let collision_points: HashSet<Vec2D> = self.active_points().into_iter().collect();
+
+        element
+            .active_points()
+            .into_iter()
+            .any(|point| collision_points.contains(&(point + offset)))
     }
 }
 
@@ -65,7 +75,7 @@ impl<'a> From<Vec<&'a dyn ViewElement>> for CollisionContainer<'a> {
     }
 }
 
-impl<'a> ViewElement for CollisionContainer<'a> {
+impl ViewElement for CollisionContainer<'_> {
     fn active_pixels(&self) -> Vec<Pixel> {
         self.elements
             .iter()
@@ -79,4 +89,11 @@ impl<'a> ViewElement for CollisionContainer<'a> {
             .flat_map(|e| e.active_points())
             .collect()
     }
+
+    fn bounding_box(&self) -> Option<Area> {
+        self.elements
+            .iter()
+            .filter_map(|e| e.bounding_box())
+            .reduce(|a, b| a.union(&b))
+    }
 }
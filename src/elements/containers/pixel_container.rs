@@ -1,5 +1,5 @@
 use crate::elements::{
-    view::{utils, ColChar, ViewElement},
+    view::{utils, Area, ColChar, ViewElement},
     Pixel, Vec2D,
 };
 
@@ -97,4 +97,8 @@ impl ViewElement for PixelContainer {
     fn active_pixels(&self) -> Vec<Pixel> {
         self.pixels.clone()
     }
+
+    fn bounding_box(&self) -> Option<Area> {
+        Area::from_points(self.pixels.iter().map(|pixel| pixel.pos))
+    }
 }
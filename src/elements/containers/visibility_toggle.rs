@@ -1,4 +1,7 @@
-use crate::elements::{view::ViewElement, Pixel, Vec2D};
+use crate::elements::{
+    view::{Area, ViewElement},
+    Pixel, Vec2D,
+};
 
 /// `VisibilityToggle` is a container for a [`ViewElement`] with a property `visible`. When blit to the view the contained element will only appear if `visible` is `true`
 #[derive(Debug, Clone)]
@@ -35,4 +38,12 @@ impl<T: ViewElement> ViewElement for VisibilityToggle<T> {
             vec![]
         }
     }
+
+    fn bounding_box(&self) -> Option<Area> {
+        if self.visible {
+            self.element.bounding_box()
+        } else {
+            None
+        }
+    }
 }
@@ -1,4 +1,4 @@
-//! This module contains basic geometry structs that implement [`ViewElement`](super::view::ViewElement), such as [`Line`] or [`Triangle`]
+//! This module contains basic geometry structs that implement [`ViewElement`](super::view::ViewElement), such as [`Line`], [`Triangle`] or [`Circle`]
 
 mod line;
 pub use line::Line;
@@ -9,5 +9,26 @@ pub use triangle::Triangle;
 mod polygon;
 pub use polygon::Polygon;
 
+mod scanline;
+pub use scanline::FillRule;
+
+mod triangulate;
+pub use triangulate::triangulate;
+
 mod rect;
 pub use rect::Rect;
+
+mod circle;
+pub use circle::{Arc, Circle, Ellipse};
+
+mod curve;
+pub use curve::{CatmullRom, CubicBezier, Path, PathSegment, QuadraticBezier};
+
+mod line_mode;
+pub use line_mode::{Antialiasing, LineMode};
+
+mod style;
+pub use style::{ShapeStyle, Stroke, StrokePattern};
+
+mod fill;
+pub use fill::{FillPattern, Hatching, PatternOrigin};
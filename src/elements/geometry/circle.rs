@@ -0,0 +1,464 @@
+use super::{style::dedup_points, Line, ShapeStyle, Stroke};
+use crate::elements::view::{ColChar, Pixel, Vec2D, ViewElement};
+use std::f64::consts::TAU;
+
+/// Scale a horizontal radius by a character width multiplier, so that shapes drawn with it look round on terminals where characters are taller than they are wide
+fn scale_radius(radius: isize, character_width_multiplier: f64) -> isize {
+    (radius as f64 * character_width_multiplier).round() as isize
+}
+
+/// The angle of the point around the centre of an ellipse with the given radii, in radians going anticlockwise from the right
+fn angle_around(point: Vec2D, centre: Vec2D, radii: Vec2D) -> f64 {
+    let offset = point - centre;
+    let (rx, ry) = (radii.x.abs().max(1) as f64, radii.y.abs().max(1) as f64);
+    (-offset.y as f64 / ry).atan2(offset.x as f64 / rx)
+}
+
+/// Sort the outline of a circle, ellipse or arc so that it goes around the centre in order, starting from the given angle
+fn sort_by_angle(points: &mut [Vec2D], centre: Vec2D, radii: Vec2D, start_angle: f64) {
+    points.sort_by(|a, b| {
+        let a = (angle_around(*a, centre, radii) - start_angle).rem_euclid(TAU);
+        let b = (angle_around(*b, centre, radii) - start_angle).rem_euclid(TAU);
+        a.total_cmp(&b)
+    });
+}
+
+/// Fill in every row of a shape between its leftmost and rightmost outline points. The shape must not have any gaps within a row, which is true of circles and ellipses
+fn fill_rows(outline: &[Vec2D]) -> Vec<Vec2D> {
+    let mut points = vec![];
+    let mut row_start = 0;
+    for i in 1..=outline.len() {
+        if i == outline.len() || outline[i].y != outline[row_start].y {
+            let y = outline[row_start].y;
+            let (x0, x1) = (outline[row_start].x, outline[i - 1].x);
+            points.extend((x0..=x1).map(|x| Vec2D::new(x, y)));
+            row_start = i;
+        }
+    }
+    points
+}
+
+/// The `Circle` takes a centre and radius and returns a circle when blit to a [`View`](super::super::View). It is drawn with the midpoint circle algorithm
+/// ```
+/// use gemini_engine::elements::{geometry::{Circle, ShapeStyle, Stroke}, view::ColChar, Vec2D};
+///
+/// // Terminal characters are about twice as tall as they are wide
+/// let circle = Circle::new(Vec2D::new(20, 10), 8, ColChar::SOLID)
+///     .with_style(ShapeStyle::stroke(Stroke::new(ColChar::SOLID)))
+///     .with_character_width_multiplier(2.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Circle {
+    /// The centre of the circle
+    pub centre: Vec2D,
+    /// The radius of the circle
+    pub radius: isize,
+    /// Whether the circle is filled in, outlined or both
+    pub style: ShapeStyle,
+    /// How much to stretch the circle horizontally to compensate for characters being taller than they are wide, like [`Viewport::character_width_multiplier`](crate::elements3d::Viewport::character_width_multiplier). With a value other than 1.0 the circle is drawn as an [`Ellipse`]
+    pub character_width_multiplier: f64,
+}
+
+impl Circle {
+    /// Create a new filled circle with no character width compensation
+    #[must_use]
+    pub const fn new(centre: Vec2D, radius: isize, fill_char: ColChar) -> Self {
+        Self {
+            centre,
+            radius,
+            style: ShapeStyle::fill(fill_char),
+            character_width_multiplier: 1.0,
+        }
+    }
+
+    /// Return the `Circle` with the chosen [`ShapeStyle`]
+    #[must_use]
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the `Circle` with the chosen character width multiplier
+    #[must_use]
+    pub const fn with_character_width_multiplier(
+        mut self,
+        character_width_multiplier: f64,
+    ) -> Self {
+        self.character_width_multiplier = character_width_multiplier;
+        self
+    }
+
+    /// Draw a circle using the midpoint circle algorithm. Returns a list of the points to print to, sorted by row
+    #[must_use]
+    pub fn draw(centre: Vec2D, radius: isize, filled: bool) -> Vec<Vec2D> {
+        let radius = radius.abs();
+        let mut points = vec![];
+
+        let (mut x, mut y) = (radius, 0);
+        let mut error = 1 - radius;
+        while x >= y {
+            for (dx, dy) in [(x, y), (y, x)] {
+                points.extend([
+                    centre + Vec2D::new(dx, dy),
+                    centre + Vec2D::new(-dx, dy),
+                    centre + Vec2D::new(dx, -dy),
+                    centre + Vec2D::new(-dx, -dy),
+                ]);
+            }
+
+            y += 1;
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+
+        dedup_points(&mut points);
+        if filled {
+            fill_rows(&points)
+        } else {
+            points
+        }
+    }
+}
+
+impl ViewElement for Circle {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let radii = Vec2D::new(
+            scale_radius(self.radius, self.character_width_multiplier),
+            self.radius,
+        );
+        let draw = |filled| {
+            if (self.character_width_multiplier - 1.0).abs() < f64::EPSILON {
+                Self::draw(self.centre, self.radius, filled)
+            } else {
+                Ellipse::draw(self.centre, radii, filled)
+            }
+        };
+
+        self.style.draw(
+            || draw(true),
+            || {
+                let mut outline = draw(false);
+                sort_by_angle(&mut outline, self.centre, radii, 0.0);
+                outline
+            },
+        )
+    }
+}
+
+/// The `Ellipse` takes a centre and a horizontal and vertical radius, and returns an ellipse when blit to a [`View`](super::super::View). It is drawn with the midpoint ellipse algorithm
+#[derive(Debug, Clone)]
+pub struct Ellipse {
+    /// The centre of the ellipse
+    pub centre: Vec2D,
+    /// The horizontal and vertical radii of the ellipse
+    pub radii: Vec2D,
+    /// Whether the ellipse is filled in, outlined or both
+    pub style: ShapeStyle,
+    /// How much to stretch the ellipse horizontally to compensate for characters being taller than they are wide, like [`Viewport::character_width_multiplier`](crate::elements3d::Viewport::character_width_multiplier)
+    pub character_width_multiplier: f64,
+}
+
+impl Ellipse {
+    /// Create a new filled ellipse with no character width compensation
+    #[must_use]
+    pub const fn new(centre: Vec2D, radii: Vec2D, fill_char: ColChar) -> Self {
+        Self {
+            centre,
+            radii,
+            style: ShapeStyle::fill(fill_char),
+            character_width_multiplier: 1.0,
+        }
+    }
+
+    /// Return the `Ellipse` with the chosen [`ShapeStyle`]
+    #[must_use]
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the `Ellipse` with the chosen character width multiplier
+    #[must_use]
+    pub const fn with_character_width_multiplier(
+        mut self,
+        character_width_multiplier: f64,
+    ) -> Self {
+        self.character_width_multiplier = character_width_multiplier;
+        self
+    }
+
+    /// The radii of the ellipse after compensating for the character width
+    fn scaled_radii(&self) -> Vec2D {
+        Vec2D::new(
+            scale_radius(self.radii.x, self.character_width_multiplier),
+            self.radii.y,
+        )
+    }
+
+    /// Draw an ellipse using the midpoint ellipse algorithm. Returns a list of the points to print to, sorted by row
+    #[must_use]
+    pub fn draw(centre: Vec2D, radii: Vec2D, filled: bool) -> Vec<Vec2D> {
+        let (rx, ry) = (radii.x.abs(), radii.y.abs());
+        if rx == 0 || ry == 0 {
+            return Line::draw(centre - Vec2D::new(rx, ry), centre + Vec2D::new(rx, ry));
+        }
+
+        let mut points = vec![];
+        let mut plot = |x: isize, y: isize| {
+            points.extend([
+                centre + Vec2D::new(x, y),
+                centre + Vec2D::new(-x, y),
+                centre + Vec2D::new(x, -y),
+                centre + Vec2D::new(-x, -y),
+            ]);
+        };
+
+        // The decision parameter is kept at four times its value so that it stays an integer
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let (mut x, mut y) = (0, ry);
+        let (mut px, mut py) = (0, 2 * rx2 * ry);
+
+        // Region 1, where the slope is shallower than -1
+        let mut p = 4 * ry2 - 4 * rx2 * ry + rx2;
+        while px < py {
+            plot(x, y);
+            x += 1;
+            px += 2 * ry2;
+            if p < 0 {
+                p += 4 * (ry2 + px);
+            } else {
+                y -= 1;
+                py -= 2 * rx2;
+                p += 4 * (ry2 + px - py);
+            }
+        }
+
+        // Region 2, where the slope is steeper than -1
+        p = ry2 * (2 * x + 1).pow(2) + 4 * rx2 * (y - 1).pow(2) - 4 * rx2 * ry2;
+        while y >= 0 {
+            plot(x, y);
+            y -= 1;
+            py -= 2 * rx2;
+            if p > 0 {
+                p += 4 * (rx2 - py);
+            } else {
+                x += 1;
+                px += 2 * ry2;
+                p += 4 * (rx2 - py + px);
+            }
+        }
+
+        dedup_points(&mut points);
+        if filled {
+            fill_rows(&points)
+        } else {
+            points
+        }
+    }
+}
+
+impl ViewElement for Ellipse {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let radii = self.scaled_radii();
+        self.style.draw(
+            || Self::draw(self.centre, radii, true),
+            || {
+                let mut outline = Self::draw(self.centre, radii, false);
+                sort_by_angle(&mut outline, self.centre, radii, 0.0);
+                outline
+            },
+        )
+    }
+}
+
+/// The `Arc` takes a centre, radius and a start and end angle, and returns part of a circle's outline when blit to a [`View`](super::super::View). Angles are in radians, going anticlockwise from the right. Create a pie slice with [`Arc::pie()`], which joins the ends of the arc to the centre
+/// ```
+/// use gemini_engine::elements::{geometry::Arc, view::ColChar, Vec2D};
+/// use std::f64::consts::PI;
+///
+/// // A pac-man
+/// let pac_man = Arc::pie(Vec2D::new(20, 10), 8, PI / 4.0, -PI / 4.0, ColChar::SOLID)
+///     .with_character_width_multiplier(2.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Arc {
+    /// The centre of the circle the arc is a part of
+    pub centre: Vec2D,
+    /// The radius of the arc
+    pub radius: isize,
+    /// The angle the arc starts at, in radians
+    pub start_angle: f64,
+    /// The angle the arc ends at, in radians. The arc always goes anticlockwise from the start angle to the end angle
+    pub end_angle: f64,
+    /// Whether the ends of the arc are joined to the centre, making a pie slice
+    pub pie: bool,
+    /// Whether the arc is filled in, outlined or both. Only pie slices can be filled
+    pub style: ShapeStyle,
+    /// How much to stretch the arc horizontally to compensate for characters being taller than they are wide, like [`Viewport::character_width_multiplier`](crate::elements3d::Viewport::character_width_multiplier)
+    pub character_width_multiplier: f64,
+}
+
+impl Arc {
+    /// Create a new arc outlined with a [`ColChar`], with no character width compensation
+    #[must_use]
+    pub const fn new(
+        centre: Vec2D,
+        radius: isize,
+        start_angle: f64,
+        end_angle: f64,
+        fill_char: ColChar,
+    ) -> Self {
+        Self {
+            centre,
+            radius,
+            start_angle,
+            end_angle,
+            pie: false,
+            style: ShapeStyle::stroke(Stroke::new(fill_char)),
+            character_width_multiplier: 1.0,
+        }
+    }
+
+    /// Create a new pie slice filled with a [`ColChar`], with no character width compensation
+    #[must_use]
+    pub fn pie(
+        centre: Vec2D,
+        radius: isize,
+        start_angle: f64,
+        end_angle: f64,
+        fill_char: ColChar,
+    ) -> Self {
+        let mut arc = Self::new(centre, radius, start_angle, end_angle, fill_char);
+        arc.pie = true;
+        arc.style = ShapeStyle::fill(fill_char);
+        arc
+    }
+
+    /// Return the `Arc` with the chosen [`ShapeStyle`]
+    #[must_use]
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the `Arc` with the chosen character width multiplier
+    #[must_use]
+    pub const fn with_character_width_multiplier(
+        mut self,
+        character_width_multiplier: f64,
+    ) -> Self {
+        self.character_width_multiplier = character_width_multiplier;
+        self
+    }
+
+    /// The radii of the arc after compensating for the character width
+    fn scaled_radii(&self) -> Vec2D {
+        Vec2D::new(
+            scale_radius(self.radius, self.character_width_multiplier),
+            self.radius,
+        )
+    }
+
+    /// Returns true if the angle is between the start and end angles, going anticlockwise. Arcs that go all the way round contain every angle
+    fn contains_angle(&self, angle: f64) -> bool {
+        if self.end_angle - self.start_angle >= TAU {
+            return true;
+        }
+        let sweep = (self.end_angle - self.start_angle).rem_euclid(TAU);
+        (angle - self.start_angle).rem_euclid(TAU) <= sweep
+    }
+
+    /// The point on the edge of the arc at the given angle
+    fn point_at(&self, angle: f64, radii: Vec2D) -> Vec2D {
+        self.centre
+            + Vec2D::new(
+                (angle.cos() * radii.x as f64).round() as isize,
+                (-angle.sin() * radii.y as f64).round() as isize,
+            )
+    }
+
+    /// Return every point inside the pie slice, or nothing if the arc isn't a pie slice
+    fn draw_fill(&self) -> Vec<Vec2D> {
+        if !self.pie {
+            return vec![];
+        }
+        let radii = self.scaled_radii();
+
+        Ellipse::draw(self.centre, radii, true)
+            .into_iter()
+            .filter(|point| {
+                *point == self.centre
+                    || self.contains_angle(angle_around(*point, self.centre, radii))
+            })
+            .chain(Line::draw(
+                self.centre,
+                self.point_at(self.start_angle, radii),
+            ))
+            .chain(Line::draw(
+                self.centre,
+                self.point_at(self.end_angle, radii),
+            ))
+            .collect()
+    }
+
+    /// Return the points along the arc in order, starting and ending at the centre if it is a pie slice
+    fn draw_outline(&self) -> Vec<Vec2D> {
+        let radii = self.scaled_radii();
+
+        let mut curve: Vec<Vec2D> = Ellipse::draw(self.centre, radii, false)
+            .into_iter()
+            .filter(|point| self.contains_angle(angle_around(*point, self.centre, radii)))
+            .collect();
+        sort_by_angle(&mut curve, self.centre, radii, self.start_angle);
+
+        if self.pie {
+            let mut start = Line::draw(self.centre, self.point_at(self.start_angle, radii));
+            start.pop();
+            let mut end = Line::draw(self.point_at(self.end_angle, radii), self.centre);
+            end.remove(0);
+            [start, curve, end].concat()
+        } else {
+            curve
+        }
+    }
+}
+
+impl ViewElement for Arc {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.style.draw(|| self.draw_fill(), || self.draw_outline())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_outline_and_fill() {
+        let outline = Circle::draw(Vec2D::ZERO, 2, false);
+        assert_eq!(outline.len(), 12);
+        assert!(outline.iter().all(|p| p.x.abs() + p.y.abs() >= 2));
+
+        let filled = Circle::draw(Vec2D::ZERO, 2, true);
+        assert_eq!(filled.len(), 21);
+
+        // With no compensation, an ellipse with equal radii matches the circle
+        assert_eq!(Ellipse::draw(Vec2D::ZERO, Vec2D::new(2, 2), false), outline);
+    }
+
+    #[test]
+    fn full_arc_is_a_circle() {
+        let arc = Arc::new(Vec2D::ZERO, 5, 0.0, TAU, ColChar::SOLID);
+        assert_eq!(
+            arc.active_points().len(),
+            Circle::draw(Vec2D::ZERO, 5, false).len()
+        );
+
+        let half = Arc::new(Vec2D::ZERO, 5, 0.0, TAU / 2.0, ColChar::SOLID);
+        assert!(half.active_points().len() < arc.active_points().len());
+    }
+}
@@ -0,0 +1,475 @@
+use super::{
+    style::{closed_path, open_path},
+    Polygon, ShapeStyle, Stroke,
+};
+use crate::elements::view::{ColChar, Pixel, Vec2D, ViewElement};
+
+/// How far a flattened curve may stray from the real curve, in cells
+const FLATNESS: f64 = 0.25;
+
+/// The deepest a curve will be split while flattening it, so that huge or broken curves still finish
+const MAX_DEPTH: u32 = 16;
+
+/// A point with floating point coordinates, used while flattening curves
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Self::new(
+            (other.x - self.x).mul_add(t, self.x),
+            (other.y - self.y).mul_add(t, self.y),
+        )
+    }
+
+    fn round(self) -> Vec2D {
+        Vec2D::new(self.x.round() as isize, self.y.round() as isize)
+    }
+
+    /// The distance of the point from the infinite line through `a` and `b`
+    fn distance_to_line(self, a: Self, b: Self) -> f64 {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let length = dx.hypot(dy);
+        if length < f64::EPSILON {
+            return (self.x - a.x).hypot(self.y - a.y);
+        }
+        dx.mul_add(a.y - self.y, -dy * (a.x - self.x)).abs() / length
+    }
+}
+
+impl From<Vec2D> for Point {
+    fn from(value: Vec2D) -> Self {
+        Self::new(value.x as f64, value.y as f64)
+    }
+}
+
+/// Split a cubic Bézier curve in half until each piece is flat enough to be drawn as a straight line, adding the end of each piece to `vertices`
+fn flatten_cubic(curve: [Point; 4], depth: u32, vertices: &mut Vec<Point>) {
+    let [p0, p1, p2, p3] = curve;
+    let flat = p1.distance_to_line(p0, p3) <= FLATNESS && p2.distance_to_line(p0, p3) <= FLATNESS;
+    if flat || depth >= MAX_DEPTH {
+        vertices.push(p3);
+        return;
+    }
+
+    // De Casteljau's algorithm
+    let half = |a: Point, b: Point| a.lerp(b, 0.5);
+    let (start_control, middle, end_control) = (half(p0, p1), half(p1, p2), half(p2, p3));
+    let (left_control, right_control) = (half(start_control, middle), half(middle, end_control));
+    let mid = half(left_control, right_control);
+
+    flatten_cubic([p0, start_control, left_control, mid], depth + 1, vertices);
+    flatten_cubic([mid, right_control, end_control, p3], depth + 1, vertices);
+}
+
+/// Turn a quadratic Bézier curve into the equivalent cubic one
+fn quadratic_to_cubic(start: Point, control: Point, end: Point) -> [Point; 4] {
+    [
+        start,
+        start.lerp(control, 2.0 / 3.0),
+        end.lerp(control, 2.0 / 3.0),
+        end,
+    ]
+}
+
+/// Round flattened vertices to cells, dropping any that land on the same cell as the one before
+fn round_vertices(vertices: &[Point]) -> Vec<Vec2D> {
+    let mut rounded: Vec<Vec2D> = vertices.iter().map(|p| p.round()).collect();
+    rounded.dedup();
+    rounded
+}
+
+/// The `QuadraticBezier` takes a start point, control point and end point, and returns a quadratic Bézier curve when blit to a [`View`](super::super::View)
+#[derive(Debug, Clone)]
+pub struct QuadraticBezier {
+    /// The start point, control point and end point of the curve
+    pub points: [Vec2D; 3],
+    /// How the curve is drawn
+    pub stroke: Stroke,
+}
+
+impl QuadraticBezier {
+    /// Create a new quadratic Bézier curve drawn with a [`ColChar`]
+    #[must_use]
+    pub const fn new(start: Vec2D, control: Vec2D, end: Vec2D, fill_char: ColChar) -> Self {
+        Self {
+            points: [start, control, end],
+            stroke: Stroke::new(fill_char),
+        }
+    }
+
+    /// Return the `QuadraticBezier` with the chosen [`Stroke`]
+    #[must_use]
+    pub const fn with_stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
+
+    /// Return the vertices of the curve after flattening it into straight lines
+    #[must_use]
+    pub fn vertices(points: [Vec2D; 3]) -> Vec<Vec2D> {
+        let [start, control, end] = points.map(Point::from);
+        let mut vertices = vec![start];
+        flatten_cubic(quadratic_to_cubic(start, control, end), 0, &mut vertices);
+        round_vertices(&vertices)
+    }
+
+    /// Draw a quadratic Bézier curve using adaptive subdivision. Returns the points along the curve in order, without any gaps
+    #[must_use]
+    pub fn draw(points: [Vec2D; 3]) -> Vec<Vec2D> {
+        open_path(&Self::vertices(points))
+    }
+}
+
+impl ViewElement for QuadraticBezier {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.stroke.draw(&Self::draw(self.points))
+    }
+}
+
+/// The `CubicBezier` takes a start point, two control points and an end point, and returns a cubic Bézier curve when blit to a [`View`](super::super::View)
+/// ```
+/// use gemini_engine::elements::{geometry::CubicBezier, view::ColChar, Vec2D};
+///
+/// let s_curve = CubicBezier::new(
+///     Vec2D::new(0, 10),
+///     Vec2D::new(20, 10),
+///     Vec2D::new(0, 0),
+///     Vec2D::new(20, 0),
+///     ColChar::SOLID,
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CubicBezier {
+    /// The start point, two control points and end point of the curve
+    pub points: [Vec2D; 4],
+    /// How the curve is drawn
+    pub stroke: Stroke,
+}
+
+impl CubicBezier {
+    /// Create a new cubic Bézier curve drawn with a [`ColChar`]
+    #[must_use]
+    pub const fn new(
+        start: Vec2D,
+        control0: Vec2D,
+        control1: Vec2D,
+        end: Vec2D,
+        fill_char: ColChar,
+    ) -> Self {
+        Self {
+            points: [start, control0, control1, end],
+            stroke: Stroke::new(fill_char),
+        }
+    }
+
+    /// Return the `CubicBezier` with the chosen [`Stroke`]
+    #[must_use]
+    pub const fn with_stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
+
+    /// Return the vertices of the curve after flattening it into straight lines
+    #[must_use]
+    pub fn vertices(points: [Vec2D; 4]) -> Vec<Vec2D> {
+        let points = points.map(Point::from);
+        let mut vertices = vec![points[0]];
+        flatten_cubic(points, 0, &mut vertices);
+        round_vertices(&vertices)
+    }
+
+    /// Draw a cubic Bézier curve using adaptive subdivision. Returns the points along the curve in order, without any gaps
+    #[must_use]
+    pub fn draw(points: [Vec2D; 4]) -> Vec<Vec2D> {
+        open_path(&Self::vertices(points))
+    }
+}
+
+impl ViewElement for CubicBezier {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.stroke.draw(&Self::draw(self.points))
+    }
+}
+
+/// The `CatmullRom` takes a list of points and returns a smooth curve passing through all of them when blit to a [`View`](super::super::View)
+#[derive(Debug, Clone)]
+pub struct CatmullRom {
+    /// The points the curve passes through
+    pub points: Vec<Vec2D>,
+    /// Whether the curve loops back round from the last point to the first
+    pub closed: bool,
+    /// How the curve is drawn
+    pub stroke: Stroke,
+}
+
+impl CatmullRom {
+    /// Create a new open Catmull-Rom spline drawn with a [`ColChar`]
+    #[must_use]
+    pub const fn new(points: Vec<Vec2D>, fill_char: ColChar) -> Self {
+        Self {
+            points,
+            closed: false,
+            stroke: Stroke::new(fill_char),
+        }
+    }
+
+    /// Return the `CatmullRom` with the chosen [`Stroke`]
+    #[must_use]
+    pub const fn with_stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
+
+    /// Return the `CatmullRom` looping back round from its last point to its first
+    #[must_use]
+    pub const fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// Return the vertices of the spline after flattening it into straight lines. If `closed` is true the last vertex joins back up with the first
+    #[must_use]
+    pub fn vertices(points: &[Vec2D], closed: bool) -> Vec<Vec2D> {
+        let n = points.len();
+        if n < 3 {
+            return points.to_vec();
+        }
+
+        let point = |i: isize| -> Point {
+            let i = if closed {
+                i.rem_euclid(n as isize) as usize
+            } else {
+                i.clamp(0, n as isize - 1) as usize
+            };
+            points[i].into()
+        };
+
+        let segments = if closed { n } else { n - 1 };
+        let mut vertices = vec![point(0)];
+        for i in 0..segments as isize {
+            // Each segment of a uniform Catmull-Rom spline is a cubic Bézier curve with these control points
+            let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+            let control0 = Point::new(p1.x + (p2.x - p0.x) / 6.0, p1.y + (p2.y - p0.y) / 6.0);
+            let control1 = Point::new(p2.x - (p3.x - p1.x) / 6.0, p2.y - (p3.y - p1.y) / 6.0);
+            flatten_cubic([p1, control0, control1, p2], 0, &mut vertices);
+        }
+
+        let mut vertices = round_vertices(&vertices);
+        if closed && vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        vertices
+    }
+
+    /// Draw a Catmull-Rom spline through the points using adaptive subdivision. Returns the points along the curve in order, without any gaps
+    #[must_use]
+    pub fn draw(points: &[Vec2D], closed: bool) -> Vec<Vec2D> {
+        let vertices = Self::vertices(points, closed);
+        if closed {
+            closed_path(&vertices)
+        } else {
+            open_path(&vertices)
+        }
+    }
+}
+
+impl ViewElement for CatmullRom {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.stroke.draw(&Self::draw(&self.points, self.closed))
+    }
+}
+
+/// A single segment of a [`Path`], starting from where the previous segment ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A straight line to the point
+    Line(Vec2D),
+    /// A quadratic Bézier curve with the first point as its control point, ending at the second point
+    Quadratic(Vec2D, Vec2D),
+    /// A cubic Bézier curve with the first two points as its control points, ending at the third point
+    Cubic(Vec2D, Vec2D, Vec2D),
+}
+
+/// The `Path` is a shape made up of straight lines and curves joined end to end, which can be left open or closed. Like other shapes it can be stroked, filled or both with a [`ShapeStyle`]. When filled, an open path is treated as if its end were joined back to its start
+/// ```
+/// use gemini_engine::elements::{geometry::{Path, ShapeStyle}, view::ColChar, Vec2D};
+///
+/// // A rounded speech bubble
+/// let bubble = Path::new(Vec2D::new(4, 0), ColChar::SOLID)
+///     .line_to(Vec2D::new(20, 0))
+///     .quadratic_to(Vec2D::new(24, 0), Vec2D::new(24, 3))
+///     .quadratic_to(Vec2D::new(24, 6), Vec2D::new(20, 6))
+///     .line_to(Vec2D::new(8, 6))
+///     .line_to(Vec2D::new(4, 9))
+///     .line_to(Vec2D::new(4, 6))
+///     .quadratic_to(Vec2D::new(0, 6), Vec2D::new(0, 3))
+///     .quadratic_to(Vec2D::new(0, 0), Vec2D::new(4, 0))
+///     .close();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Path {
+    /// Where the path starts
+    pub start: Vec2D,
+    /// The segments making up the path, in order
+    pub segments: Vec<PathSegment>,
+    /// Whether the end of the path is joined back to its start when stroked
+    pub closed: bool,
+    /// Whether the path is filled in, outlined or both
+    pub style: ShapeStyle,
+}
+
+impl Path {
+    /// Create a new, empty, open path outlined with a [`ColChar`]. Add segments to it with [`Path::line_to()`], [`Path::quadratic_to()`] and [`Path::cubic_to()`]
+    #[must_use]
+    pub const fn new(start: Vec2D, fill_char: ColChar) -> Self {
+        Self {
+            start,
+            segments: vec![],
+            closed: false,
+            style: ShapeStyle::stroke(Stroke::new(fill_char)),
+        }
+    }
+
+    /// Return the `Path` with the chosen [`ShapeStyle`]
+    #[must_use]
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the `Path` with a straight line added to the end
+    #[must_use]
+    pub fn line_to(mut self, end: Vec2D) -> Self {
+        self.segments.push(PathSegment::Line(end));
+        self
+    }
+
+    /// Return the `Path` with a quadratic Bézier curve added to the end
+    #[must_use]
+    pub fn quadratic_to(mut self, control: Vec2D, end: Vec2D) -> Self {
+        self.segments.push(PathSegment::Quadratic(control, end));
+        self
+    }
+
+    /// Return the `Path` with a cubic Bézier curve added to the end
+    #[must_use]
+    pub fn cubic_to(mut self, control0: Vec2D, control1: Vec2D, end: Vec2D) -> Self {
+        self.segments
+            .push(PathSegment::Cubic(control0, control1, end));
+        self
+    }
+
+    /// Return the `Path` with its end joined back to its start
+    #[must_use]
+    pub const fn close(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Return the vertices of the path after flattening its curves into straight lines. If the path is closed and already ends where it started, the repeated last vertex is left out
+    #[must_use]
+    pub fn vertices(&self) -> Vec<Vec2D> {
+        let mut vertices = vec![Point::from(self.start)];
+        let mut current = Point::from(self.start);
+        for segment in &self.segments {
+            let end = match *segment {
+                PathSegment::Line(end) => {
+                    vertices.push(end.into());
+                    end
+                }
+                PathSegment::Quadratic(control, end) => {
+                    let curve = quadratic_to_cubic(current, control.into(), end.into());
+                    flatten_cubic(curve, 0, &mut vertices);
+                    end
+                }
+                PathSegment::Cubic(control0, control1, end) => {
+                    let curve = [current, control0.into(), control1.into(), end.into()];
+                    flatten_cubic(curve, 0, &mut vertices);
+                    end
+                }
+            };
+            current = end.into();
+        }
+
+        let mut vertices = round_vertices(&vertices);
+        if self.closed && vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        vertices
+    }
+
+    /// Return the points along the path in order, without any gaps
+    #[must_use]
+    pub fn outline(&self) -> Vec<Vec2D> {
+        let vertices = self.vertices();
+        if self.closed {
+            closed_path(&vertices)
+        } else {
+            open_path(&vertices)
+        }
+    }
+}
+
+impl ViewElement for Path {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.style
+            .draw(|| Polygon::draw(&self.vertices()), || self.outline())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns true if every point in the path touches the one after it
+    fn is_connected(path: &[Vec2D]) -> bool {
+        path.windows(2).all(|pair| {
+            let step = pair[1] - pair[0];
+            step.x.abs() <= 1 && step.y.abs() <= 1
+        })
+    }
+
+    #[test]
+    fn curves_have_no_gaps() {
+        let cubic = CubicBezier::draw([
+            Vec2D::new(0, 20),
+            Vec2D::new(60, 20),
+            Vec2D::new(-20, 0),
+            Vec2D::new(40, 0),
+        ]);
+        assert!(is_connected(&cubic));
+        assert_eq!(cubic.first(), Some(&Vec2D::new(0, 20)));
+        assert_eq!(cubic.last(), Some(&Vec2D::new(40, 0)));
+
+        let points = [Vec2D::new(0, 0), Vec2D::new(10, 8), Vec2D::new(20, 0)];
+        let spline = CatmullRom::draw(&points, false);
+        assert!(is_connected(&spline));
+        assert!(points.iter().all(|p| spline.contains(p)));
+
+        // A straight quadratic curve is the same as a line
+        let straight = QuadraticBezier::draw([Vec2D::ZERO, Vec2D::new(5, 0), Vec2D::new(10, 0)]);
+        assert_eq!(straight.len(), 11);
+    }
+
+    #[test]
+    fn open_paths_keep_their_last_segment() {
+        let path = Path::new(Vec2D::ZERO, ColChar::SOLID)
+            .line_to(Vec2D::new(5, 0))
+            .line_to(Vec2D::new(5, 5))
+            .line_to(Vec2D::ZERO);
+        assert_eq!(path.vertices().len(), 4);
+        assert!(path.outline().contains(&Vec2D::new(2, 2)));
+
+        let closed = path.close();
+        assert_eq!(closed.vertices().len(), 3);
+        assert!(closed.outline().contains(&Vec2D::new(2, 2)));
+    }
+}
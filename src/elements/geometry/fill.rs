@@ -0,0 +1,218 @@
+use crate::elements::{
+    ascii::remove_leading_newlines,
+    view::{ColChar, Modifier, Vec2D},
+};
+use std::{fmt, sync::Arc};
+
+/// Where the tiles of a [`FillPattern`] are lined up from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PatternOrigin {
+    /// Patterns are lined up with the top-left corner of the [`View`](super::super::View), so neighbouring shapes with the same pattern join up seamlessly and the pattern stays still when a shape moves
+    #[default]
+    World,
+    /// Patterns are lined up with the top-left corner of the shape's bounding box, so the pattern moves along with the shape
+    Shape,
+}
+
+/// The direction of the lines in a [`FillPattern::Hatch`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hatching {
+    /// Diagonal lines going up to the right, drawn with `/`
+    Forward,
+    /// Diagonal lines going down to the right, drawn with `\`
+    Backward,
+    /// Both diagonals, crossing at `x`
+    Cross,
+    /// Horizontal lines drawn with `-`
+    Horizontal,
+    /// Vertical lines drawn with `|`
+    Vertical,
+    /// Horizontal and vertical lines, crossing at `+`
+    Grid,
+}
+
+impl Hatching {
+    /// Return the character at the position in hatching with lines the given number of cells apart, or `None` if the position is between lines
+    #[must_use]
+    pub const fn char_at(&self, pos: Vec2D, spacing: isize) -> Option<char> {
+        let spacing = if spacing < 1 { 1 } else { spacing };
+        let forward = (pos.x + pos.y).rem_euclid(spacing) == 0;
+        let backward = (pos.x - pos.y).rem_euclid(spacing) == 0;
+        let horizontal = pos.y.rem_euclid(spacing) == 0;
+        let vertical = pos.x.rem_euclid(spacing) == 0;
+
+        let (first, second) = match self {
+            Self::Forward => ((forward, '/'), None),
+            Self::Backward => ((backward, '\\'), None),
+            Self::Horizontal => ((horizontal, '-'), None),
+            Self::Vertical => ((vertical, '|'), None),
+            Self::Cross => ((forward, '/'), Some((backward, '\\', 'x'))),
+            Self::Grid => ((horizontal, '-'), Some((vertical, '|', '+'))),
+        };
+
+        match (first, second) {
+            ((true, _), Some((true, _, crossing))) => Some(crossing),
+            ((true, c), _) | (_, Some((true, c, _))) => Some(c),
+            _ => None,
+        }
+    }
+}
+
+/// What a shape is filled with. Every pattern except [`FillPattern::Solid`] is tiled from a [`PatternOrigin`], which is set with [`ShapeStyle::with_pattern_origin()`](super::ShapeStyle::with_pattern_origin())
+/// ```
+/// use gemini_engine::elements::{geometry::{FillPattern, Hatching, Rect, ShapeStyle}, view::{ColChar, Modifier}, Vec2D};
+///
+/// let bricks = FillPattern::texture("
+/// ___|
+/// _|__", Modifier::None);
+/// let wall = Rect::new(Vec2D::ZERO, Vec2D::new(20, 6), ColChar::SOLID)
+///     .with_style(ShapeStyle::pattern(bricks));
+///
+/// let shaded = FillPattern::Hatch(Hatching::Forward, 2, ColChar::new('/', Modifier::None));
+///
+/// let gradient = FillPattern::custom(|pos| {
+///     let shade = ['.', ':', '+', '#'][pos.x.rem_euclid(4) as usize];
+///     ColChar::new(shade, Modifier::None)
+/// });
+/// ```
+#[derive(Clone)]
+pub enum FillPattern {
+    /// Every cell uses the same [`ColChar`]
+    Solid(ColChar),
+    /// The rows of an ASCII texture, tiled across the shape. Spaces are left empty, so shapes behind show through. Use [`FillPattern::texture()`] to create one from a multi-line string
+    Texture(Vec<Vec<char>>, Modifier),
+    /// Alternate between the two [`ColChar`]s in blocks of the given size
+    Checkerboard(ColChar, ColChar, Vec2D),
+    /// Lines in the chosen direction, the given number of cells apart. The [`ColChar`]'s modifier is used, but its character is replaced with one that matches the direction of the lines. Cells between the lines are left empty
+    Hatch(Hatching, isize, ColChar),
+    /// Call the function with each cell's position relative to the pattern's origin to get its [`ColChar`]
+    Custom(Arc<dyn Fn(Vec2D) -> ColChar + Send + Sync>),
+}
+
+impl FillPattern {
+    /// Create a [`FillPattern::Texture`] from a multi-line string. All newlines at the beginning of the texture will be removed, and shorter lines are padded with spaces so that the texture tiles as a rectangle
+    #[must_use]
+    pub fn texture(texture: &str, modifier: Modifier) -> Self {
+        let mut rows: Vec<Vec<char>> = remove_leading_newlines(texture)
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, ' ');
+        }
+
+        Self::Texture(rows, modifier)
+    }
+
+    /// Create a [`FillPattern::Custom`] from a function. The function must be `Send` and `Sync` so that shapes using the pattern can still be moved between threads
+    #[must_use]
+    pub fn custom(function: impl Fn(Vec2D) -> ColChar + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(function))
+    }
+
+    /// Return the [`ColChar`] at a position relative to the pattern's origin, or `None` if the cell should be left empty
+    #[must_use]
+    pub fn sample(&self, pos: Vec2D) -> Option<ColChar> {
+        match self {
+            Self::Solid(fill_char) => Some(*fill_char),
+            Self::Texture(rows, modifier) => {
+                let row = rows.get(pos.y.rem_euclid(rows.len().max(1) as isize) as usize)?;
+                let c = *row.get(pos.x.rem_euclid(row.len().max(1) as isize) as usize)?;
+                (c != ' ').then_some(ColChar::new(c, *modifier))
+            }
+            Self::Checkerboard(first, second, size) => {
+                let block = pos.x.div_euclid(size.x.max(1)) + pos.y.div_euclid(size.y.max(1));
+                Some(if block.rem_euclid(2) == 0 {
+                    *first
+                } else {
+                    *second
+                })
+            }
+            Self::Hatch(hatching, spacing, fill_char) => hatching
+                .char_at(pos, *spacing)
+                .map(|c| ColChar::new(c, fill_char.modifier)),
+            Self::Custom(function) => Some(function(pos)),
+        }
+    }
+}
+
+impl From<ColChar> for FillPattern {
+    fn from(fill_char: ColChar) -> Self {
+        Self::Solid(fill_char)
+    }
+}
+
+impl fmt::Debug for FillPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solid(fill_char) => f.debug_tuple("Solid").field(fill_char).finish(),
+            Self::Texture(rows, modifier) => f
+                .debug_tuple("Texture")
+                .field(rows)
+                .field(modifier)
+                .finish(),
+            Self::Checkerboard(first, second, size) => f
+                .debug_tuple("Checkerboard")
+                .field(first)
+                .field(second)
+                .field(size)
+                .finish(),
+            Self::Hatch(hatching, spacing, fill_char) => f
+                .debug_tuple("Hatch")
+                .field(hatching)
+                .field(spacing)
+                .field(fill_char)
+                .finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(pattern: &FillPattern, y: isize, width: isize) -> String {
+        (0..width)
+            .map(|x| {
+                pattern
+                    .sample(Vec2D::new(x, y))
+                    .map_or(' ', |c| c.text_char)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn patterns_tile() {
+        let texture = FillPattern::texture("\nab\nc", Modifier::None);
+        assert_eq!(chars(&texture, 0, 5), "ababa");
+        assert_eq!(chars(&texture, -1, 5), "c c c");
+
+        let a = ColChar::new('a', Modifier::None);
+        let b = ColChar::new('b', Modifier::None);
+        let checkerboard = FillPattern::Checkerboard(a, b, Vec2D::new(2, 1));
+        assert_eq!(chars(&checkerboard, 0, 6), "aabbaa");
+        assert_eq!(chars(&checkerboard, 1, 6), "bbaabb");
+
+        let cross = FillPattern::Hatch(Hatching::Cross, 2, ColChar::SOLID);
+        assert_eq!(chars(&cross, 0, 4), "x x ");
+        let grid = FillPattern::Hatch(Hatching::Grid, 3, ColChar::SOLID);
+        assert_eq!(chars(&grid, 0, 4), "+--+");
+        assert_eq!(chars(&grid, 1, 4), "|  |");
+    }
+
+    #[test]
+    fn styled_shapes_are_send_and_sync() {
+        fn is_send_sync<T: Send + Sync>() {}
+        is_send_sync::<FillPattern>();
+        is_send_sync::<crate::elements::Rect>();
+        is_send_sync::<crate::elements::Line>();
+        is_send_sync::<crate::elements::Polygon>();
+        is_send_sync::<crate::elements::Triangle>();
+        is_send_sync::<crate::elements::geometry::Circle>();
+        is_send_sync::<crate::elements::geometry::Arc>();
+        is_send_sync::<crate::elements::geometry::Path>();
+        is_send_sync::<crate::elements::Tilemap>();
+    }
+}
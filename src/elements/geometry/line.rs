@@ -1,4 +1,5 @@
-use crate::elements::view::{utils, ColChar, Pixel, Vec2D, ViewElement};
+use super::Stroke;
+use crate::elements::view::{Area, ColChar, Pixel, Vec2D, ViewElement};
 
 /// The `Line` takes two [`Vec2D`]s and returns a line between those vertices when blit to a [`View`](super::super::View)
 pub struct Line {
@@ -6,21 +7,41 @@ pub struct Line {
     pub pos0: Vec2D,
     /// The end position of the line
     pub pos1: Vec2D,
-    /// The [`ColChar`] used to colour the line
-    pub fill_char: ColChar,
+    /// How the line is drawn, including its [`ColChar`], width and pattern
+    pub stroke: Stroke,
 }
 
 impl Line {
-    /// Create a new line with a start and end point and a [`ColChar`]
+    /// Create a new solid line one cell wide with a start and end point and a [`ColChar`]
     #[must_use]
     pub const fn new(pos0: Vec2D, pos1: Vec2D, fill_char: ColChar) -> Self {
         Self {
             pos0,
             pos1,
-            fill_char,
+            stroke: Stroke::new(fill_char),
         }
     }
 
+    /// Return the `Line` with the chosen [`Stroke`]
+    #[must_use]
+    pub const fn with_stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
+
+    /// Return the [`ColChar`] the `Line` is drawn with
+    #[deprecated = "Line is now drawn with a `Stroke`, use `Line.stroke.fill_char` instead"]
+    #[must_use]
+    pub const fn fill_char(&self) -> ColChar {
+        self.stroke.fill_char
+    }
+
+    /// Change the [`ColChar`] the `Line` is drawn with
+    #[deprecated = "Line is now drawn with a `Stroke`, use `Line.stroke.fill_char` instead"]
+    pub fn set_fill_char(&mut self, fill_char: ColChar) {
+        self.stroke.fill_char = fill_char;
+    }
+
     /// Draw a line using Bresenham's line algorithm. Returns a list of the pixels to print to
     #[must_use]
     pub fn draw(pos0: Vec2D, pos1: Vec2D) -> Vec<Vec2D> {
@@ -43,17 +64,17 @@ impl Line {
             if e2 >= dy {
                 if x == x1 {
                     break;
-                };
+                }
                 error += dy;
                 x += sx;
-            };
+            }
             if e2 <= dx {
                 if y == y1 {
                     break;
-                };
+                }
                 error += dx;
                 y += sy;
-            };
+            }
         }
 
         points
@@ -62,10 +83,14 @@ impl Line {
 
 impl ViewElement for Line {
     fn active_pixels(&self) -> Vec<Pixel> {
-        utils::points_to_pixels(&self.active_points(), self.fill_char)
+        self.stroke.draw(&Self::draw(self.pos0, self.pos1))
     }
 
     fn active_points(&self) -> Vec<Vec2D> {
-        Self::draw(self.pos0, self.pos1)
+        self.stroke.apply(&Self::draw(self.pos0, self.pos1))
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        Area::from_points([self.pos0, self.pos1]).map(|area| self.stroke.bounds(area))
     }
 }
@@ -0,0 +1,174 @@
+use crate::elements::view::{ColChar, Colour, Modifier, Vec2D};
+
+/// How many points either side of a point on a path are used to work out the path's local direction
+const FIT_WINDOW: usize = 3;
+
+/// The local shape of a path at one of its points, found by fitting a straight line through the points around it
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LocalFit {
+    /// The direction of the path, or zero if the point is on its own
+    pub direction: Vec2D,
+    /// How far the fitted line is from the centre of the cell along the minor axis, between -0.5 and 0.5. For mostly horizontal paths a positive offset means the line passes below the centre, and for mostly vertical paths it means the line passes to the right
+    pub offset: f64,
+}
+
+impl LocalFit {
+    /// Fit a line through the points around the point at the index of the path
+    pub fn at(path: &[Vec2D], index: usize) -> Self {
+        let point = path[index];
+        let start = path[index.saturating_sub(FIT_WINDOW)];
+        let end = path[(index + FIT_WINDOW).min(path.len() - 1)];
+        let direction = end - start;
+
+        let offset = if direction.x.abs() >= direction.y.abs() && direction.x != 0 {
+            let slope = direction.y as f64 / direction.x as f64;
+            ((point.x - start.x) as f64).mul_add(slope, (start.y - point.y) as f64)
+        } else if direction.y != 0 {
+            let slope = direction.x as f64 / direction.y as f64;
+            ((point.y - start.y) as f64).mul_add(slope, (start.x - point.x) as f64)
+        } else {
+            0.0
+        };
+
+        Self {
+            direction,
+            offset: offset.clamp(-0.5, 0.5),
+        }
+    }
+
+    /// The cell next to the point on the other side of the fitted line, and how much of it the line covers. Together with the point's own coverage of `1.0 - offset.abs()` this is Xiaolin Wu's line algorithm
+    pub fn neighbour(&self, point: Vec2D) -> (Vec2D, f64) {
+        let side = if self.offset < 0.0 { -1 } else { 1 };
+        let step = if self.direction.x.abs() >= self.direction.y.abs() {
+            Vec2D::new(0, side)
+        } else {
+            Vec2D::new(side, 0)
+        };
+        (point + step, self.offset.abs())
+    }
+
+    /// The angle of the path in degrees between 0 and 180, going anticlockwise from the right
+    fn angle(&self) -> f64 {
+        (-self.direction.y as f64)
+            .atan2(self.direction.x as f64)
+            .to_degrees()
+            .rem_euclid(180.0)
+    }
+}
+
+/// How an anti-aliased line shows cells it only partly covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Antialiasing {
+    /// Pick a character from the ramp, which goes from the faintest character to the fullest. The stroke's modifier is kept
+    Ramp(&'static str),
+    /// Keep the stroke's character, but blend its colour toward the given background colour. This only works with strokes that use [`Modifier::Colour`], otherwise only cells that are at least half covered are drawn
+    Blend(Colour),
+}
+
+impl Antialiasing {
+    /// A ramp of characters from faintest to fullest, used by default
+    pub const DEFAULT_RAMP: &'static str = ".:-=+*#%@";
+
+    /// Return the [`ColChar`] to draw a cell that the line covers by the given amount between 0.0 and 1.0, or `None` if the cell shouldn't be drawn at all
+    #[must_use]
+    pub fn shade(&self, fill_char: ColChar, coverage: f64) -> Option<ColChar> {
+        let coverage = coverage.clamp(0.0, 1.0);
+        match self {
+            Self::Ramp(ramp) => {
+                let chars: Vec<char> = ramp.chars().collect();
+                let index = (coverage * chars.len() as f64).round() as usize;
+                let c = *chars.get(index.checked_sub(1)?)?;
+                Some(ColChar::new(c, fill_char.modifier))
+            }
+            Self::Blend(background) => match fill_char.modifier {
+                Modifier::Colour(colour) if coverage > 0.05 => Some(ColChar::new(
+                    fill_char.text_char,
+                    Modifier::Colour(background.lerp(colour, coverage)),
+                )),
+                _ if coverage >= 0.5 => Some(fill_char),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl Default for Antialiasing {
+    /// A [`Antialiasing::Ramp`] of [`Antialiasing::DEFAULT_RAMP`]
+    fn default() -> Self {
+        Self::Ramp(Self::DEFAULT_RAMP)
+    }
+}
+
+/// How the characters along a line or outline are chosen
+/// ```
+/// use gemini_engine::elements::{geometry::{Line, LineMode, Stroke}, view::ColChar, Vec2D};
+///
+/// let line = Line::new(Vec2D::new(0, 0), Vec2D::new(12, 3), ColChar::SOLID)
+///     .with_stroke(Stroke::new(ColChar::SOLID).with_mode(LineMode::Ascii));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineMode {
+    /// Every cell uses the stroke's character
+    #[default]
+    Fill,
+    /// Characters are picked from `-`, `_`, `` ` ``, `/`, `|`, `\` and `.` based on the local slope of the line and where it passes through the cell, for a hand-drawn ASCII art look
+    Ascii,
+    /// Characters are picked from `─`, `╱`, `│`, `╲` and `·` based on the local slope of the line
+    BoxDrawing,
+    /// The line is anti-aliased with Xiaolin Wu's algorithm, drawing the cells either side of it with a strength that depends on how much of each cell the line covers
+    AntiAliased(Antialiasing),
+}
+
+impl LineMode {
+    /// Pick the character for the point at the index of a path, which should be in order along the line. Returns `None` for [`LineMode::Fill`] and [`LineMode::AntiAliased`], meaning the stroke's own character should be used
+    #[must_use]
+    pub fn pick(&self, path: &[Vec2D], index: usize) -> Option<char> {
+        let (horizontal, rising, vertical, falling, dot) = match self {
+            Self::Fill | Self::AntiAliased(_) => return None,
+            Self::Ascii => ('-', '/', '|', '\\', '.'),
+            Self::BoxDrawing => ('─', '╱', '│', '╲', '·'),
+        };
+
+        let fit = LocalFit::at(path, index);
+        if fit.direction == Vec2D::ZERO {
+            return Some(dot);
+        }
+
+        let c = match fit.angle() {
+            // Only ASCII has characters for the top and bottom of a cell
+            a if !(22.5..157.5).contains(&a) => match fit.offset {
+                o if *self == Self::Ascii && o > 0.2 => '_',
+                o if *self == Self::Ascii && o < -0.2 => '`',
+                _ => horizontal,
+            },
+            a if a < 67.5 => rising,
+            a if a < 112.5 => vertical,
+            _ => falling,
+        };
+
+        Some(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::geometry::Line;
+
+    fn ascii_line(pos0: Vec2D, pos1: Vec2D) -> String {
+        let path = Line::draw(pos0, pos1);
+        (0..path.len())
+            .filter_map(|i| LineMode::Ascii.pick(&path, i))
+            .collect()
+    }
+
+    #[test]
+    fn ascii_characters_follow_slope() {
+        assert_eq!(ascii_line(Vec2D::ZERO, Vec2D::new(4, 0)), "-----");
+        assert_eq!(ascii_line(Vec2D::ZERO, Vec2D::new(0, 3)), "||||");
+        assert_eq!(ascii_line(Vec2D::ZERO, Vec2D::new(3, 3)), "\\\\\\\\");
+        assert_eq!(ascii_line(Vec2D::new(0, 3), Vec2D::new(3, 0)), "////");
+        assert_eq!(ascii_line(Vec2D::ZERO, Vec2D::ZERO), ".");
+        assert_eq!(LineMode::Fill.pick(&[Vec2D::ZERO], 0), None);
+    }
+}
@@ -1,113 +1,147 @@
-use super::Triangle;
-use crate::elements::view::{utils, ColChar, Pixel, Vec2D, ViewElement};
-
-fn is_left_turn(p0: Vec2D, p1: Vec2D, p2: Vec2D) -> bool {
-    let v1 = p1 - p0;
-    let v2 = p2 - p0;
-    v1.cross(v2) > 0
-}
-
-fn is_ear(vertex: Vec2D, prev_vertex: Vec2D, next_vertex: Vec2D, polygon: &[Vec2D]) -> bool {
-    for i in 0..polygon.len() {
-        let p1 = polygon[i];
-        let p2 = polygon[(i + 1) % polygon.len()];
-        let p3 = polygon[(i + 2) % polygon.len()];
-
-        if p1 != vertex
-            && p2 != vertex
-            && p3 != vertex
-            && is_left_turn(vertex, p1, p2)
-            && is_left_turn(vertex, p2, p3)
-            && is_left_turn(vertex, p3, p1)
-        {
-            let triangle_area = (p1 - p2).cross(p3 - p2).abs();
-            if triangle_area > 0 {
-                let p = (vertex - prev_vertex).cross(next_vertex - prev_vertex);
-                return (p1 - prev_vertex).cross(p1 - next_vertex) > 0 && p > 0;
-            }
-        }
-    }
-    false
-}
-
-/// The `Polygon` takes a vec of [`Vec2D`]s and returns a polygon with those vertices when blit to a [`View`](super::super::View)
+use super::{
+    scanline::{fill_contours, signed_area},
+    style::closed_path,
+    FillRule, ShapeStyle,
+};
+use crate::elements::view::{Area, ColChar, Pixel, Vec2D, ViewElement};
+
+/// The `Polygon` takes a vec of [`Vec2D`]s and returns a polygon with those vertices when blit to a [`View`](super::super::View). Polygons can be concave, cross over themselves and have holes cut out of them
+/// ```
+/// use gemini_engine::elements::{geometry::{FillRule, Polygon}, view::ColChar, Vec2D};
+///
+/// let square = vec![Vec2D::new(0, 0), Vec2D::new(10, 0), Vec2D::new(10, 10), Vec2D::new(0, 10)];
+/// let window = vec![Vec2D::new(3, 3), Vec2D::new(7, 3), Vec2D::new(7, 7), Vec2D::new(3, 7)];
+///
+/// let frame = Polygon::new(square, ColChar::SOLID).with_hole(window);
+///
+/// let star = vec![
+///     Vec2D::new(5, 0),
+///     Vec2D::new(8, 10),
+///     Vec2D::new(0, 4),
+///     Vec2D::new(10, 4),
+///     Vec2D::new(2, 10),
+/// ];
+/// // With the even-odd rule the pentagon in the middle of the star is left empty
+/// let outline_star = Polygon::new(star, ColChar::SOLID).with_fill_rule(FillRule::EvenOdd);
+/// ```
 pub struct Polygon {
     /// The points that make up the polygon
     pub points: Vec<Vec2D>,
-    /// The [`ColChar`] used to fill the polygon
-    pub fill_char: ColChar,
+    /// Contours cut out of the polygon. Holes are always cut out regardless of which way round they go
+    pub holes: Vec<Vec<Vec2D>>,
+    /// How overlapping parts of the polygon are filled
+    pub fill_rule: FillRule,
+    /// Whether the polygon is filled in, outlined or both
+    pub style: ShapeStyle,
 }
 
 impl Polygon {
-    /// Create a new polygon
+    /// Create a new polygon filled with a [`ColChar`], with no holes and using the non-zero fill rule
     #[must_use]
     pub const fn new(points: Vec<Vec2D>, fill_char: ColChar) -> Self {
-        Self { points, fill_char }
+        Self {
+            points,
+            holes: vec![],
+            fill_rule: FillRule::NonZero,
+            style: ShapeStyle::fill(fill_char),
+        }
     }
 
-    /// Split a polygon up into triangles using the ear cutting algorithm. Returns a vec of coordinate sets for each triangle
+    /// Return the `Polygon` with the chosen [`ShapeStyle`]
     #[must_use]
-    pub fn triangulate(vertices: &[Vec2D]) -> Vec<[Vec2D; 3]> {
-        let mut triangles = Vec::new();
-        let n = vertices.len();
-
-        if n < 3 {
-            return triangles;
-        }
-
-        let mut remaining_vertices = vertices.to_vec();
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
 
-        while remaining_vertices.len() > 3 {
-            let mut ear_index = 0;
-            for i in 0..remaining_vertices.len() {
-                let prev_index = (i + remaining_vertices.len() - 1) % remaining_vertices.len();
-                let next_index = (i + 1) % remaining_vertices.len();
+    /// Return the [`ColChar`] the `Polygon` is drawn with, as returned by [`ShapeStyle::fill_char()`]
+    #[deprecated = "Polygon is now drawn with a `ShapeStyle`, use `Polygon.style.fill_char()` instead"]
+    #[must_use]
+    pub fn fill_char(&self) -> Option<ColChar> {
+        self.style.fill_char()
+    }
 
-                let vertex = remaining_vertices[i];
-                let prev_vertex = remaining_vertices[prev_index];
-                let next_vertex = remaining_vertices[next_index];
+    /// Change the [`ColChar`] the `Polygon` is drawn with, as done by [`ShapeStyle::set_fill_char()`]
+    #[deprecated = "Polygon is now drawn with a `ShapeStyle`, use `Polygon.style.set_fill_char()` instead"]
+    pub fn set_fill_char(&mut self, fill_char: ColChar) {
+        self.style.set_fill_char(fill_char);
+    }
 
-                if is_ear(vertex, prev_vertex, next_vertex, &remaining_vertices) {
-                    ear_index = i;
-                    break;
-                }
-            }
+    /// Return the `Polygon` with a hole cut out of it
+    #[must_use]
+    pub fn with_hole(mut self, hole: Vec<Vec2D>) -> Self {
+        self.holes.push(hole);
+        self
+    }
 
-            let ear_vertex = remaining_vertices[ear_index];
-            let prev_index = (ear_index + remaining_vertices.len() - 1) % remaining_vertices.len();
-            let next_index = (ear_index + 1) % remaining_vertices.len();
-            let prev_vertex = remaining_vertices[prev_index];
-            let next_vertex = remaining_vertices[next_index];
+    /// Return the `Polygon` with the chosen [`FillRule`]
+    #[must_use]
+    pub const fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
 
-            triangles.push([prev_vertex, ear_vertex, next_vertex]);
-            remaining_vertices.remove(ear_index);
-        }
+    /// Split a polygon up into triangles using the ear cutting algorithm. Returns a vec of coordinate sets for each triangle
+    #[deprecated = "Please use `geometry::triangulate` instead, which reports polygons it can't split"]
+    #[must_use]
+    pub fn triangulate(vertices: &[Vec2D]) -> Vec<[Vec2D; 3]> {
+        super::triangulate(vertices).unwrap_or_default()
+    }
 
-        triangles.push([
-            remaining_vertices[0],
-            remaining_vertices[1],
-            remaining_vertices[2],
-        ]);
+    /// Draw a polygon from points using the non-zero fill rule. Supports convex, concave and self-intersecting polygons
+    #[must_use]
+    pub fn draw(vertices: &[Vec2D]) -> Vec<Vec2D> {
+        fill_contours(&[vertices], FillRule::NonZero)
+    }
 
-        triangles
+    /// Draw a shape made of several contours using a scanline rasteriser. Whether overlapping or nested contours are filled depends on the [`FillRule`] and which way round each contour goes. The edges of every contour are included, so neighbouring shapes meet without gaps
+    #[must_use]
+    pub fn draw_contours(contours: &[Vec<Vec2D>], fill_rule: FillRule) -> Vec<Vec2D> {
+        let contours: Vec<&[Vec2D]> = contours.iter().map(Vec::as_slice).collect();
+        fill_contours(&contours, fill_rule)
     }
 
-    /// Draw a polygon from points. Supports convex and concave polygons
+    /// Return the points around the edge of a polygon, in order
     #[must_use]
-    pub fn draw(vertices: &[Vec2D]) -> Vec<Vec2D> {
-        Self::triangulate(vertices)
-            .iter()
-            .flat_map(|corners| Triangle::draw(*corners))
-            .collect()
+    pub fn outline(vertices: &[Vec2D]) -> Vec<Vec2D> {
+        closed_path(vertices)
+    }
+
+    /// Return the polygon's points followed by its holes, with each hole turned to go the opposite way round to the polygon so that it is cut out with either fill rule
+    fn contours(&self) -> Vec<Vec<Vec2D>> {
+        let orientation = signed_area(&self.points).signum();
+        let mut contours = vec![self.points.clone()];
+        for hole in &self.holes {
+            let mut hole = hole.clone();
+            if signed_area(&hole).signum() == orientation {
+                hole.reverse();
+            }
+            contours.push(hole);
+        }
+        contours
     }
 }
 
 impl ViewElement for Polygon {
     fn active_pixels(&self) -> Vec<Pixel> {
-        utils::points_to_pixels(&self.active_points(), self.fill_char)
+        let contours = self.contours();
+        self.style.draw_contours(
+            || Self::draw_contours(&contours, self.fill_rule),
+            || contours.iter().map(|c| Self::outline(c)).collect(),
+        )
     }
 
     fn active_points(&self) -> Vec<Vec2D> {
-        Self::draw(&self.points)
+        let contours = self.contours();
+        self.style.points_contours(
+            || Self::draw_contours(&contours, self.fill_rule),
+            || contours.iter().map(|c| Self::outline(c)).collect(),
+        )
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        // Holes can only cut into the polygon, so they never make it any bigger
+        self.style
+            .bounds(Area::from_points(self.points.iter().copied()))
     }
 }
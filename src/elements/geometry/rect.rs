@@ -1,4 +1,5 @@
-use crate::elements::view::{utils, ColChar, Pixel, Vec2D, ViewElement};
+use super::{style::closed_path, ShapeStyle};
+use crate::elements::view::{Area, ColChar, Pixel, Vec2D, ViewElement};
 
 /// The `Rect` takes a position and size, and returns a rectangle at that position with the given width and size when blit to a [`View`](super::super::View)
 pub struct Rect {
@@ -6,21 +7,41 @@ pub struct Rect {
     pub pos: Vec2D,
     /// The size of the `Rect`, extending from [`Rect::pos`]
     pub size: Vec2D,
-    /// The [`ColChar`] used to fill the rectangle
-    pub fill_char: ColChar,
+    /// Whether the rectangle is filled in, outlined or both
+    pub style: ShapeStyle,
 }
 
 impl Rect {
-    /// Create a new rectangle using a given position, size and [`ColChar`]
+    /// Create a new rectangle using a given position, size and [`ColChar`] to fill it with
     #[must_use]
     pub const fn new(pos: Vec2D, size: Vec2D, fill_char: ColChar) -> Self {
         Self {
             pos,
             size,
-            fill_char,
+            style: ShapeStyle::fill(fill_char),
         }
     }
 
+    /// Return the `Rect` with the chosen [`ShapeStyle`]
+    #[must_use]
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the [`ColChar`] the `Rect` is drawn with, as returned by [`ShapeStyle::fill_char()`]
+    #[deprecated = "Rect is now drawn with a `ShapeStyle`, use `Rect.style.fill_char()` instead"]
+    #[must_use]
+    pub fn fill_char(&self) -> Option<ColChar> {
+        self.style.fill_char()
+    }
+
+    /// Change the [`ColChar`] the `Rect` is drawn with, as done by [`ShapeStyle::set_fill_char()`]
+    #[deprecated = "Rect is now drawn with a `ShapeStyle`, use `Rect.style.set_fill_char()` instead"]
+    pub fn set_fill_char(&mut self, fill_char: ColChar) {
+        self.style.set_fill_char(fill_char);
+    }
+
     /// Create a new rectangle between two position to fill with a [`ColChar`]
     #[must_use]
     pub fn new_from_to(pos0: Vec2D, pos1: Vec2D, fill_char: ColChar) -> Self {
@@ -34,14 +55,34 @@ impl Rect {
             .flat_map(|x| (0..size.y).map(move |y| pos + Vec2D { x, y }))
             .collect()
     }
+
+    /// Return the points around the edge of a rectangle with a given position and size, going clockwise from the top-left corner
+    #[must_use]
+    pub fn outline(pos: Vec2D, size: Vec2D) -> Vec<Vec2D> {
+        if size.x <= 0 || size.y <= 0 {
+            return vec![];
+        }
+        let end = pos + size - Vec2D::new(1, 1);
+        closed_path(&[pos, Vec2D::new(end.x, pos.y), end, Vec2D::new(pos.x, end.y)])
+    }
 }
 
 impl ViewElement for Rect {
     fn active_pixels(&self) -> Vec<Pixel> {
-        utils::points_to_pixels(&self.active_points(), self.fill_char)
+        self.style.draw(
+            || Self::draw(self.pos, self.size),
+            || Self::outline(self.pos, self.size),
+        )
     }
 
     fn active_points(&self) -> Vec<Vec2D> {
-        Self::draw(self.pos, self.size)
+        self.style.points(
+            || Self::draw(self.pos, self.size),
+            || Self::outline(self.pos, self.size),
+        )
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        self.style.bounds(Some(Area::new(self.pos, self.size)))
     }
 }
@@ -0,0 +1,118 @@
+use super::style::closed_path;
+use crate::elements::view::Vec2D;
+
+/// Decides which parts of a shape with overlapping or nested contours are inside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// A point is inside the shape if a line from it to infinity crosses the contours an odd number of times, so overlapping areas alternate between filled and empty
+    EvenOdd,
+    /// A point is inside the shape if the contours wind around it a different number of times clockwise and anticlockwise, so overlapping areas going the same way stay filled
+    #[default]
+    NonZero,
+}
+
+/// Twice the signed area of the contour, which is positive if it goes clockwise on screen
+pub(super) fn signed_area(contour: &[Vec2D]) -> isize {
+    (0..contour.len())
+        .map(|i| contour[i].cross(contour[(i + 1) % contour.len()]))
+        .sum()
+}
+
+/// Fill the contours with a scanline rasteriser, sampling the centre of each cell. Each contour is treated as closed, and the edges of every contour are included so that neighbouring shapes meet without gaps
+pub(super) fn fill_contours(contours: &[&[Vec2D]], rule: FillRule) -> Vec<Vec2D> {
+    let edges: Vec<(Vec2D, Vec2D)> = contours
+        .iter()
+        .flat_map(|contour| {
+            (0..contour.len()).map(|i| (contour[i], contour[(i + 1) % contour.len()]))
+        })
+        .filter(|(a, b)| a.y != b.y)
+        .collect();
+
+    let mut points: Vec<Vec2D> = contours
+        .iter()
+        .flat_map(|contour| closed_path(contour))
+        .collect();
+
+    let Some(min_y) = edges.iter().map(|(a, b)| a.y.min(b.y)).min() else {
+        return points;
+    };
+    let max_y = edges
+        .iter()
+        .map(|(a, b)| a.y.max(b.y))
+        .max()
+        .unwrap_or(min_y);
+
+    let mut crossings: Vec<(f64, isize)> = vec![];
+    for y in min_y..max_y {
+        crossings.clear();
+        for (a, b) in &edges {
+            // Each edge covers the rows from its top up to but not including its bottom, so that shared vertices aren't counted twice
+            let (top, bottom, winding) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+            if y < top.y || y >= bottom.y {
+                continue;
+            }
+            let t = (y - top.y) as f64 / (bottom.y - top.y) as f64;
+            let x = ((bottom.x - top.x) as f64).mul_add(t, top.x as f64);
+            crossings.push((x, winding));
+        }
+        crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        for pair in crossings.windows(2) {
+            winding += pair[0].1;
+            let inside = match rule {
+                FillRule::EvenOdd => winding % 2 != 0,
+                FillRule::NonZero => winding != 0,
+            };
+            if inside {
+                let (start, end) = (pair[0].0.ceil() as isize, pair[1].0.floor() as isize);
+                points.extend((start..=end).map(|x| Vec2D::new(x, y)));
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_rules() {
+        let outer = [
+            Vec2D::new(0, 0),
+            Vec2D::new(8, 0),
+            Vec2D::new(8, 8),
+            Vec2D::new(0, 8),
+        ];
+        let inner = [
+            Vec2D::new(3, 3),
+            Vec2D::new(5, 3),
+            Vec2D::new(5, 5),
+            Vec2D::new(3, 5),
+        ];
+        let inside = |points: &[Vec2D], p: Vec2D| points.contains(&p);
+
+        let even_odd = fill_contours(&[&outer, &inner], FillRule::EvenOdd);
+        assert!(inside(&even_odd, Vec2D::new(1, 1)));
+        assert!(!inside(&even_odd, Vec2D::new(4, 4)));
+        // The hole's edges are still drawn
+        assert!(inside(&even_odd, Vec2D::new(3, 4)));
+
+        // Both contours go the same way, so the hole is filled with the non-zero rule
+        let non_zero = fill_contours(&[&outer, &inner], FillRule::NonZero);
+        assert!(inside(&non_zero, Vec2D::new(4, 4)));
+
+        // A bowtie crosses over itself without any gaps
+        let bowtie = [
+            Vec2D::new(0, 0),
+            Vec2D::new(6, 6),
+            Vec2D::new(6, 0),
+            Vec2D::new(0, 6),
+        ];
+        let filled = fill_contours(&[&bowtie], FillRule::NonZero);
+        assert!(inside(&filled, Vec2D::new(1, 3)));
+        assert!(!inside(&filled, Vec2D::new(3, 1)));
+    }
+}
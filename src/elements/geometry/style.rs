@@ -0,0 +1,502 @@
+use super::{line_mode::LocalFit, FillPattern, LineMode, PatternOrigin};
+use crate::elements::view::{utils, Area, ColChar, Pixel, Vec2D};
+use std::collections::{HashMap, HashSet};
+
+/// Sort the points by row and remove any duplicates
+pub(super) fn dedup_points(points: &mut Vec<Vec2D>) {
+    points.sort_unstable_by_key(|p| (p.y, p.x));
+    points.dedup();
+}
+
+/// Join the vertices with lines into a closed path, going back from the last vertex to the first. Each point is only included once, so the path can be used with a [`StrokePattern`]
+pub(super) fn closed_path(vertices: &[Vec2D]) -> Vec<Vec2D> {
+    match vertices {
+        [] => vec![],
+        [vertex] => vec![*vertex],
+        _ => (0..vertices.len())
+            .flat_map(|i| {
+                let mut line = super::Line::draw(vertices[i], vertices[(i + 1) % vertices.len()]);
+                line.pop();
+                line
+            })
+            .collect(),
+    }
+}
+
+/// Join the vertices with lines into an open path from the first vertex to the last. Each point is only included once, so the path can be used with a [`StrokePattern`]
+pub(super) fn open_path(vertices: &[Vec2D]) -> Vec<Vec2D> {
+    let mut path: Vec<Vec2D> = vertices
+        .windows(2)
+        .flat_map(|pair| {
+            let mut line = super::Line::draw(pair[0], pair[1]);
+            line.pop();
+            line
+        })
+        .collect();
+    path.extend(vertices.last());
+    path
+}
+
+/// Which points along a [`Stroke`] are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrokePattern {
+    /// Every point is drawn
+    #[default]
+    Solid,
+    /// Dashes of the first number of points, separated by gaps of the second number of points
+    Dashed(usize, usize),
+    /// Every other point is drawn
+    Dotted,
+}
+
+impl StrokePattern {
+    /// Returns true if the point at the index along the stroke should be drawn
+    #[must_use]
+    pub const fn is_drawn(&self, index: usize) -> bool {
+        match self {
+            Self::Solid => true,
+            Self::Dashed(on, off) => *on + *off == 0 || index % (*on + *off) < *on,
+            Self::Dotted => index % 2 == 0,
+        }
+    }
+}
+
+/// How the outline of a shape or a [`Line`](super::Line) is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stroke {
+    /// The [`ColChar`] used to draw the stroke
+    pub fill_char: ColChar,
+    /// The width of the stroke in cells. The stroke is centred on the outline, so wider strokes extend both inside and outside the shape
+    pub width: isize,
+    /// Which points along the stroke are drawn
+    pub pattern: StrokePattern,
+    /// How the characters along the stroke are chosen
+    pub mode: LineMode,
+}
+
+impl Stroke {
+    /// Create a new solid `Stroke` one cell wide
+    #[must_use]
+    pub const fn new(fill_char: ColChar) -> Self {
+        Self {
+            fill_char,
+            width: 1,
+            pattern: StrokePattern::Solid,
+            mode: LineMode::Fill,
+        }
+    }
+
+    /// Return the `Stroke` with the chosen width
+    #[must_use]
+    pub const fn with_width(mut self, width: isize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Return the `Stroke` with the chosen pattern
+    #[must_use]
+    pub const fn with_pattern(mut self, pattern: StrokePattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Return the `Stroke` with the chosen [`LineMode`]
+    #[must_use]
+    pub const fn with_mode(mut self, mode: LineMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The offsets covered by a single point of the stroke, given its width
+    fn brush(&self) -> impl Iterator<Item = Vec2D> {
+        let start = -(self.width.max(1) - 1) / 2;
+        let end = start + self.width.max(1);
+        (start..end).flat_map(move |y| (start..end).map(move |x| Vec2D::new(x, y)))
+    }
+
+    /// Return the area covered by the stroke when drawn along a path that fits inside the given area
+    pub(super) fn bounds(&self, path_bounds: Area) -> Area {
+        let width = self.width.max(1);
+        // Anti-aliased strokes can also partly cover the cells just outside of the brush
+        let spread = isize::from(matches!(self.mode, LineMode::AntiAliased(_)));
+        let before = (width - 1) / 2 + spread;
+        let extra = width - 1 + spread * 2;
+
+        Area::new(
+            path_bounds.pos - Vec2D::new(before, before),
+            path_bounds.size + Vec2D::new(extra, extra),
+        )
+    }
+
+    /// Apply the stroke's pattern and width to a path of points, which should be in order along the path
+    #[must_use]
+    pub fn apply(&self, path: &[Vec2D]) -> Vec<Vec2D> {
+        let mut points: Vec<Vec2D> = path
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.pattern.is_drawn(*i))
+            .flat_map(|(_, point)| self.brush().map(move |offset| *point + offset))
+            .collect();
+        if self.width > 1 {
+            dedup_points(&mut points);
+        }
+
+        points
+    }
+
+    /// Return every cell the stroke covers along a path of points, along with how much of the cell it covers between 0.0 and 1.0. Only strokes with [`LineMode::AntiAliased`] partly cover cells, and the result is sorted by row
+    #[must_use]
+    pub fn coverage(&self, path: &[Vec2D]) -> Vec<(Vec2D, f64)> {
+        let LineMode::AntiAliased(_) = self.mode else {
+            return self.apply(path).into_iter().map(|p| (p, 1.0)).collect();
+        };
+
+        let mut cells: HashMap<Vec2D, f64> = HashMap::new();
+        for (i, point) in path.iter().enumerate() {
+            if !self.pattern.is_drawn(i) {
+                continue;
+            }
+            let fit = LocalFit::at(path, i);
+            let (neighbour, neighbour_coverage) = fit.neighbour(*point);
+            for (cell, coverage) in [
+                (*point, 1.0 - fit.offset.abs()),
+                (neighbour, neighbour_coverage),
+            ] {
+                for offset in self.brush() {
+                    let entry = cells.entry(cell + offset).or_insert(0.0);
+                    *entry = entry.max(coverage);
+                }
+            }
+        }
+
+        let mut cells: Vec<(Vec2D, f64)> = cells.into_iter().collect();
+        cells.sort_unstable_by_key(|(p, _)| (p.y, p.x));
+        cells
+    }
+
+    /// Draw the stroke along a path of points, which should be in order along the path, choosing each point's character with the stroke's [`LineMode`]
+    #[must_use]
+    pub fn draw(&self, path: &[Vec2D]) -> Vec<Pixel> {
+        match self.mode {
+            LineMode::Fill => return utils::points_to_pixels(&self.apply(path), self.fill_char),
+            LineMode::AntiAliased(antialiasing) => {
+                return self
+                    .coverage(path)
+                    .into_iter()
+                    .filter_map(|(pos, coverage)| {
+                        let fill_char = antialiasing.shade(self.fill_char, coverage)?;
+                        Some(Pixel::new(pos, fill_char))
+                    })
+                    .collect();
+            }
+            LineMode::Ascii | LineMode::BoxDrawing => (),
+        }
+
+        let mut pixels = vec![];
+        for (i, point) in path.iter().enumerate() {
+            if !self.pattern.is_drawn(i) {
+                continue;
+            }
+            let mut fill_char = self.fill_char;
+            if let Some(c) = self.mode.pick(path, i) {
+                fill_char.text_char = c;
+            }
+            pixels.extend(
+                self.brush()
+                    .map(|offset| Pixel::new(*point + offset, fill_char)),
+            );
+        }
+
+        pixels
+    }
+}
+
+/// Whether a shape is filled in, outlined or both, and with which [`ColChar`]s or [`FillPattern`]. The stroke is drawn on top of the fill
+/// ```
+/// use gemini_engine::elements::{geometry::{FillPattern, PatternOrigin, Rect, ShapeStyle, Stroke, StrokePattern}, view::{ColChar, Modifier}, Vec2D};
+///
+/// let style = ShapeStyle::fill_and_stroke(
+///     ColChar::new('.', Modifier::None),
+///     Stroke::new(ColChar::SOLID).with_pattern(StrokePattern::Dashed(3, 1)),
+/// );
+/// let rect = Rect::new(Vec2D::ZERO, Vec2D::new(10, 5), ColChar::SOLID).with_style(style);
+///
+/// let checkerboard = FillPattern::Checkerboard(ColChar::SOLID, ColChar::EMPTY, Vec2D::new(2, 1));
+/// let style = ShapeStyle::pattern(checkerboard).with_pattern_origin(PatternOrigin::Shape);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShapeStyle {
+    /// The [`FillPattern`] used to fill the shape, if any
+    pub fill: Option<FillPattern>,
+    /// The outline of the shape, if any
+    pub stroke: Option<Stroke>,
+    /// Where the fill pattern is lined up from. This has no effect on [`FillPattern::Solid`] fills
+    pub pattern_origin: PatternOrigin,
+}
+
+impl ShapeStyle {
+    /// Create a `ShapeStyle` that only fills the shape
+    #[must_use]
+    pub const fn fill(fill_char: ColChar) -> Self {
+        Self::pattern(FillPattern::Solid(fill_char))
+    }
+
+    /// Create a `ShapeStyle` that only fills the shape with a [`FillPattern`]
+    #[must_use]
+    pub const fn pattern(pattern: FillPattern) -> Self {
+        Self {
+            fill: Some(pattern),
+            stroke: None,
+            pattern_origin: PatternOrigin::World,
+        }
+    }
+
+    /// Create a `ShapeStyle` that only outlines the shape
+    #[must_use]
+    pub const fn stroke(stroke: Stroke) -> Self {
+        Self {
+            fill: None,
+            stroke: Some(stroke),
+            pattern_origin: PatternOrigin::World,
+        }
+    }
+
+    /// Create a `ShapeStyle` that fills the shape and outlines it
+    #[must_use]
+    pub const fn fill_and_stroke(fill_char: ColChar, stroke: Stroke) -> Self {
+        Self {
+            fill: Some(FillPattern::Solid(fill_char)),
+            stroke: Some(stroke),
+            pattern_origin: PatternOrigin::World,
+        }
+    }
+
+    /// Return the `ShapeStyle` with the shape filled with a [`FillPattern`], keeping its stroke
+    #[must_use]
+    pub fn with_fill(mut self, pattern: FillPattern) -> Self {
+        self.fill = Some(pattern);
+        self
+    }
+
+    /// Return the `ShapeStyle` with the chosen [`PatternOrigin`]
+    #[must_use]
+    pub const fn with_pattern_origin(mut self, pattern_origin: PatternOrigin) -> Self {
+        self.pattern_origin = pattern_origin;
+        self
+    }
+
+    /// Return the [`ColChar`] of a solid fill, or of the stroke if the shape isn't filled with a solid [`ColChar`]. Returns `None` if the style uses neither
+    #[must_use]
+    pub fn fill_char(&self) -> Option<ColChar> {
+        match &self.fill {
+            Some(FillPattern::Solid(fill_char)) => Some(*fill_char),
+            _ => self.stroke.map(|stroke| stroke.fill_char),
+        }
+    }
+
+    /// Draw the shape with a different [`ColChar`], replacing the fill with a solid one if the shape is filled and changing the stroke's character if it's outlined. A style that draws nothing is changed to fill the shape
+    pub fn set_fill_char(&mut self, fill_char: ColChar) {
+        if self.fill.is_some() || self.stroke.is_none() {
+            self.fill = Some(FillPattern::Solid(fill_char));
+        }
+        if let Some(stroke) = &mut self.stroke {
+            stroke.fill_char = fill_char;
+        }
+    }
+
+    /// Return the pixels for every point inside a shape, sampling the fill pattern relative to the pattern's origin. Points the pattern leaves empty are skipped
+    fn fill_pixels(&self, pattern: &FillPattern, points: &[Vec2D]) -> Vec<Pixel> {
+        if let FillPattern::Solid(fill_char) = pattern {
+            return utils::points_to_pixels(points, *fill_char);
+        }
+
+        let origin = match self.pattern_origin {
+            PatternOrigin::World => Vec2D::ZERO,
+            PatternOrigin::Shape => Vec2D::new(
+                points.iter().map(|p| p.x).min().unwrap_or(0),
+                points.iter().map(|p| p.y).min().unwrap_or(0),
+            ),
+        };
+
+        points
+            .iter()
+            .filter_map(|p| Some(Pixel::new(*p, pattern.sample(*p - origin)?)))
+            .collect()
+    }
+
+    /// Return the area covered by a shape drawn in this style, given the area covered by the shape's outline. Returns `None` if the style draws nothing
+    pub(super) fn bounds(&self, shape_bounds: Option<Area>) -> Option<Area> {
+        if self.fill.is_none() && self.stroke.is_none() {
+            return None;
+        }
+        let shape_bounds = shape_bounds.filter(|area| !area.is_empty())?;
+
+        Some(
+            self.stroke
+                .map_or(shape_bounds, |stroke| stroke.bounds(shape_bounds)),
+        )
+    }
+
+    /// Draw a shape in this style. `fill` should return every point inside the shape and `outline` should return the points around its edge in order, and each is only called if it is needed
+    pub fn draw(
+        &self,
+        fill: impl FnOnce() -> Vec<Vec2D>,
+        outline: impl FnOnce() -> Vec<Vec2D>,
+    ) -> Vec<Pixel> {
+        self.draw_contours(fill, || vec![outline()])
+    }
+
+    /// Return the points covered by a shape drawn in this style, skipping the work of choosing characters where possible. Takes the same arguments as [`ShapeStyle::draw()`]
+    pub fn points(
+        &self,
+        fill: impl FnOnce() -> Vec<Vec2D>,
+        outline: impl FnOnce() -> Vec<Vec2D>,
+    ) -> Vec<Vec2D> {
+        self.points_contours(fill, || vec![outline()])
+    }
+
+    /// Return the points covered by a shape with several separate outlines drawn in this style. Takes the same arguments as [`ShapeStyle::draw_contours()`]
+    pub fn points_contours(
+        &self,
+        fill: impl FnOnce() -> Vec<Vec2D>,
+        outlines: impl FnOnce() -> Vec<Vec<Vec2D>>,
+    ) -> Vec<Vec2D> {
+        match (&self.fill, self.stroke) {
+            (Some(FillPattern::Solid(_)), None) => fill(),
+            (None, Some(stroke)) if stroke.mode == LineMode::Fill => outlines()
+                .iter()
+                .flat_map(|outline| stroke.apply(outline))
+                .collect(),
+            _ => utils::pixels_to_points(&self.draw_contours(fill, outlines)),
+        }
+    }
+
+    /// Draw a shape with several separate outlines in this style, such as a shape with holes. Each outline is stroked on its own
+    pub fn draw_contours(
+        &self,
+        fill: impl FnOnce() -> Vec<Vec2D>,
+        outlines: impl FnOnce() -> Vec<Vec<Vec2D>>,
+    ) -> Vec<Pixel> {
+        let mut pixels = vec![];
+        let mut inside = HashSet::new();
+        if let Some(pattern) = &self.fill {
+            let points = fill();
+            pixels.extend(self.fill_pixels(pattern, &points));
+            inside.extend(points);
+        }
+
+        let Some(stroke) = self.stroke else {
+            return pixels;
+        };
+        for outline in outlines() {
+            match stroke.mode {
+                // Only blend the edge outwards, so that the inside of the shape stays solid
+                LineMode::AntiAliased(antialiasing) if !inside.is_empty() => {
+                    pixels.extend(stroke.coverage(&outline).into_iter().filter_map(
+                        |(pos, coverage)| {
+                            let coverage = if inside.contains(&pos) { 1.0 } else { coverage };
+                            Some(Pixel::new(
+                                pos,
+                                antialiasing.shade(stroke.fill_char, coverage)?,
+                            ))
+                        },
+                    ));
+                }
+                _ => pixels.extend(stroke.draw(&outline)),
+            }
+        }
+
+        pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{
+        geometry::{Antialiasing, Line, Polygon, Rect, Triangle},
+        view::ViewElement,
+    };
+
+    #[test]
+    fn stroke_pattern_and_width() {
+        let outline = Rect::outline(Vec2D::ZERO, Vec2D::new(4, 3));
+        assert_eq!(outline.len(), 10);
+        assert_eq!(outline[4], Vec2D::new(3, 1));
+
+        let dashed = Stroke::new(ColChar::SOLID).with_pattern(StrokePattern::Dashed(2, 1));
+        assert_eq!(dashed.apply(&outline).len(), 7);
+
+        let wide = Stroke::new(ColChar::SOLID).with_width(3);
+        assert_eq!(wide.apply(&[Vec2D::ZERO]).len(), 9);
+    }
+
+    #[test]
+    fn antialiased_coverage() {
+        let stroke =
+            Stroke::new(ColChar::SOLID).with_mode(LineMode::AntiAliased(Antialiasing::Ramp(".:#")));
+        let coverage = stroke.coverage(&Line::draw(Vec2D::ZERO, Vec2D::new(4, 1)));
+
+        let at = |x, y| {
+            coverage
+                .iter()
+                .find(|(p, _)| *p == Vec2D::new(x, y))
+                .map_or(0.0, |(_, c)| *c)
+        };
+        assert!((at(1, 0) - 0.75).abs() < 1e-9);
+        assert!((at(1, 1) - 0.25).abs() < 1e-9);
+        assert!((at(4, 1) - 1.0).abs() < 1e-9);
+
+        let pixels = stroke.draw(&Line::draw(Vec2D::ZERO, Vec2D::new(4, 1)));
+        let chars: String = pixels.iter().map(|p| p.fill_char.text_char).collect();
+        assert_eq!(chars, "#::..::#");
+    }
+
+    #[test]
+    fn points_match_pixels_and_recolouring() {
+        let stroke = Stroke::new(ColChar::SOLID).with_width(2);
+        let styles = [
+            ShapeStyle::fill(ColChar::SOLID),
+            ShapeStyle::stroke(stroke),
+            ShapeStyle::fill_and_stroke(ColChar::SOLID, stroke),
+        ];
+        for style in styles {
+            let mut rect = Rect::new(Vec2D::new(1, 1), Vec2D::new(4, 3), ColChar::SOLID)
+                .with_style(style.clone());
+            let triangle = Triangle::new(
+                Vec2D::ZERO,
+                Vec2D::new(8, 2),
+                Vec2D::new(3, 6),
+                ColChar::SOLID,
+            )
+            .with_style(style.clone());
+            let polygon = Polygon::new(
+                vec![
+                    Vec2D::ZERO,
+                    Vec2D::new(9, 0),
+                    Vec2D::new(9, 9),
+                    Vec2D::new(0, 9),
+                ],
+                ColChar::SOLID,
+            )
+            .with_hole(vec![Vec2D::new(3, 3), Vec2D::new(6, 3), Vec2D::new(6, 6)])
+            .with_style(style);
+
+            let elements: [&dyn ViewElement; 3] = [&rect, &triangle, &polygon];
+            for element in elements {
+                let mut points = element.active_points();
+                dedup_points(&mut points);
+                let mut expected = utils::pixels_to_points(&element.active_pixels());
+                dedup_points(&mut expected);
+                assert_eq!(points, expected);
+            }
+
+            rect.style.set_fill_char(ColChar::EMPTY);
+            assert_eq!(rect.style.fill_char(), Some(ColChar::EMPTY));
+            assert!(rect
+                .active_pixels()
+                .iter()
+                .all(|pixel| pixel.fill_char == ColChar::EMPTY));
+        }
+    }
+}
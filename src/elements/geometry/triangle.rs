@@ -1,13 +1,13 @@
-use crate::elements::view::{utils, ColChar, Pixel, Vec2D, ViewElement};
+use crate::elements::view::{Area, ColChar, Pixel, Vec2D, ViewElement};
 
-use super::Line;
+use super::{style::closed_path, Line, ShapeStyle};
 
 /// The `Triangle` takes three [`Vec2D`]s and returns a triangle with those vertices when blit to a [`View`](super::super::View)
 pub struct Triangle {
     /// The 3 cornes of the triangle
     pub corners: [Vec2D; 3],
-    /// The [`ColChar`] used to fill the triange
-    pub fill_char: ColChar,
+    /// Whether the triangle is filled in, outlined or both
+    pub style: ShapeStyle,
 }
 
 impl Triangle {
@@ -20,7 +20,30 @@ impl Triangle {
     /// Create a new triangle from an array of `Vec2D`s and a `ColChar`
     #[must_use]
     pub const fn with_array(corners: [Vec2D; 3], fill_char: ColChar) -> Self {
-        Self { corners, fill_char }
+        Self {
+            corners,
+            style: ShapeStyle::fill(fill_char),
+        }
+    }
+
+    /// Return the `Triangle` with the chosen [`ShapeStyle`]
+    #[must_use]
+    pub fn with_style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the [`ColChar`] the `Triangle` is drawn with, as returned by [`ShapeStyle::fill_char()`]
+    #[deprecated = "Triangle is now drawn with a `ShapeStyle`, use `Triangle.style.fill_char()` instead"]
+    #[must_use]
+    pub fn fill_char(&self) -> Option<ColChar> {
+        self.style.fill_char()
+    }
+
+    /// Change the [`ColChar`] the `Triangle` is drawn with, as done by [`ShapeStyle::set_fill_char()`]
+    #[deprecated = "Triangle is now drawn with a `ShapeStyle`, use `Triangle.style.set_fill_char()` instead"]
+    pub fn set_fill_char(&mut self, fill_char: ColChar) {
+        self.style.set_fill_char(fill_char);
     }
 
     /// Return the triangle's points as an array
@@ -56,7 +79,7 @@ impl Triangle {
         values
     }
 
-    /// Takes three corner [`Vec2D`]s and returns the points you should plot to the screen to make a triangle
+    /// Takes three corner [`Vec2D`]s and returns the points you should plot to the screen to make a filled triangle, including its edges
     #[must_use]
     pub fn draw(corners: [Vec2D; 3]) -> Vec<Vec2D> {
         let mut points = vec![];
@@ -87,21 +110,33 @@ impl Triangle {
             }
         }
 
-        // Outline (will probably remove later)
+        // The scanlines stop short of the right and bottom edges, so add the edges to make sure neighbouring triangles meet without gaps
         points.append(&mut Line::draw(corners[0], corners[1]));
         points.append(&mut Line::draw(corners[1], corners[2]));
         points.append(&mut Line::draw(corners[2], corners[0]));
 
         points
     }
+
+    /// Return the points around the edge of a triangle, in order
+    #[must_use]
+    pub fn outline(corners: [Vec2D; 3]) -> Vec<Vec2D> {
+        closed_path(&corners)
+    }
 }
 
 impl ViewElement for Triangle {
     fn active_pixels(&self) -> Vec<Pixel> {
-        utils::points_to_pixels(&self.active_points(), self.fill_char)
+        self.style
+            .draw(|| Self::draw(self.corners), || Self::outline(self.corners))
     }
 
     fn active_points(&self) -> Vec<Vec2D> {
-        Self::draw(self.corners)
+        self.style
+            .points(|| Self::draw(self.corners), || Self::outline(self.corners))
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        self.style.bounds(Area::from_points(self.corners))
     }
 }
@@ -0,0 +1,82 @@
+use super::scanline::signed_area;
+use crate::elements::view::Vec2D;
+
+/// Returns true if the point is inside or on the edge of the triangle
+fn triangle_contains(triangle: [Vec2D; 3], point: Vec2D) -> bool {
+    let [a, b, c] = triangle;
+    let d0 = (b - a).cross(point - a);
+    let d1 = (c - b).cross(point - b);
+    let d2 = (a - c).cross(point - c);
+    let has_negative = d0 < 0 || d1 < 0 || d2 < 0;
+    let has_positive = d0 > 0 || d1 > 0 || d2 > 0;
+    !(has_negative && has_positive)
+}
+
+/// Split a simple polygon up into triangles using the ear clipping algorithm. The polygon can be convex or concave and go either way round, but must not cross over itself. To draw polygons that do, use [`Polygon`](super::Polygon), which fills shapes with a scanline rasteriser instead
+/// ```
+/// use gemini_engine::elements::{geometry::triangulate, Vec2D};
+///
+/// let arrow = [
+///     Vec2D::new(0, 0),
+///     Vec2D::new(10, 5),
+///     Vec2D::new(0, 10),
+///     Vec2D::new(4, 5),
+/// ];
+///
+/// assert_eq!(triangulate(&arrow).map(|triangles| triangles.len()), Ok(2));
+/// ```
+///
+/// # Errors
+/// Returns an error if no ear can be found, which happens when the polygon crosses over itself
+pub fn triangulate(vertices: &[Vec2D]) -> Result<Vec<[Vec2D; 3]>, String> {
+    let mut triangles = vec![];
+    let mut remaining = vertices.to_vec();
+    remaining.dedup();
+    if remaining.len() > 1 && remaining.first() == remaining.last() {
+        remaining.pop();
+    }
+    let orientation = signed_area(&remaining).signum();
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let corners = |i: usize| {
+            [
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            ]
+        };
+
+        let ear = (0..n).find_map(|i| {
+            let triangle = corners(i);
+            let turn = (triangle[1] - triangle[0]).cross(triangle[2] - triangle[1]);
+            if turn == 0 {
+                // The vertex is in a straight line with its neighbours, so it can be dropped without adding a triangle
+                return Some((i, None));
+            }
+            if turn.signum() != orientation {
+                return None;
+            }
+            let blocked = remaining
+                .iter()
+                .any(|p| !triangle.contains(p) && triangle_contains(triangle, *p));
+            (!blocked).then_some((i, Some(triangle)))
+        });
+
+        let Some((index, triangle)) = ear else {
+            return Err(String::from(
+                "No ear found, the polygon might be crossing over itself",
+            ));
+        };
+        triangles.extend(triangle);
+        remaining.remove(index);
+    }
+
+    if let [a, b, c] = remaining[..] {
+        if (b - a).cross(c - b) != 0 {
+            triangles.push([a, b, c]);
+        }
+    }
+
+    Ok(triangles)
+}
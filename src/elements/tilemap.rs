@@ -0,0 +1,32 @@
+//! This module holds the [`Tilemap`], a grid of tiles for building levels out of a small set of reusable pieces. Each cell of a [`TileLayer`] holds the [`TileId`] of a [`Tile`] from a [`Tileset`], which decides what the tile looks like and what it does, such as whether it's solid or how much damage it deals
+//!
+//! Maps can be built in code or loaded from a plain-text map file, where a legend maps each character to a tile:
+//! ```
+//! use gemini_engine::elements::{tilemap::{Tile, Tilemap, Tileset}, view::{ColChar, Modifier}, Vec2D};
+//!
+//! let tileset = Tileset::new(Vec2D::new(2, 1))
+//!     .with_tile(1, Tile::texture("[]", Modifier::None).with_solid(true))
+//!     .with_tile(2, Tile::new(ColChar::new('^', Modifier::None)).with_damage(5));
+//!
+//! let map = Tilemap::parse(Vec2D::ZERO, "
+//! [legend]
+//! W = 1
+//! ^ = 2
+//! [layer]
+//! W    W
+//! W^^^^W
+//! WWWWWW", tileset).unwrap();
+//!
+//! assert_eq!(map.size(), Vec2D::new(6, 3));
+//! assert!(map.is_solid(Vec2D::new(1, 0)));
+//! assert_eq!(map.damage_at(Vec2D::new(4, 1)), 5);
+//! ```
+
+mod layer;
+pub use layer::TileLayer;
+
+mod map;
+pub use map::Tilemap;
+
+mod tileset;
+pub use tileset::{Tile, TileId, Tileset};
@@ -0,0 +1,96 @@
+use super::TileId;
+use crate::elements::view::{Area, Vec2D};
+
+/// A grid of [`TileId`]s, one for each tile of a [`Tilemap`](super::Tilemap). Cells can also be left empty. Positions in a `TileLayer` are measured in tiles, not cells, starting from the top-left tile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileLayer {
+    size: Vec2D,
+    tiles: Vec<Option<TileId>>,
+    /// Whether the layer is drawn. Hidden layers still count for collisions, which is useful for invisible walls
+    pub visible: bool,
+}
+
+impl TileLayer {
+    /// Create a new visible `TileLayer` of the given width and height in tiles, with every tile empty
+    #[must_use]
+    pub fn new(size: Vec2D) -> Self {
+        let size = Vec2D::new(size.x.max(0), size.y.max(0));
+        Self {
+            size,
+            tiles: vec![None; (size.x * size.y) as usize],
+            visible: true,
+        }
+    }
+
+    /// Create a new visible `TileLayer` from rows of tiles. Shorter rows are padded with empty tiles
+    #[must_use]
+    pub fn from_rows(rows: Vec<Vec<Option<TileId>>>) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut layer = Self::new(Vec2D::new(width as isize, rows.len() as isize));
+        for (y, row) in rows.into_iter().enumerate() {
+            let start = y * width;
+            layer.tiles[start..start + row.len()].copy_from_slice(&row);
+        }
+
+        layer
+    }
+
+    /// Return the `TileLayer` with its [`visible`](TileLayer::visible) field set to the chosen value
+    #[must_use]
+    pub const fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Return the width and height of the layer in tiles
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        self.size
+    }
+
+    fn index(&self, pos: Vec2D) -> Option<usize> {
+        Area::new(Vec2D::ZERO, self.size)
+            .contains(pos)
+            .then_some((pos.y * self.size.x + pos.x) as usize)
+    }
+
+    /// Return the [`TileId`] at the position, or `None` if the tile is empty or outside the layer
+    #[must_use]
+    pub fn get(&self, pos: Vec2D) -> Option<TileId> {
+        self.index(pos).and_then(|i| self.tiles[i])
+    }
+
+    /// Set the tile at the position, or empty it with `None`
+    ///
+    /// # Errors
+    /// Returns an error if the position is outside the layer
+    pub fn set(&mut self, pos: Vec2D, tile: Option<TileId>) -> Result<(), String> {
+        let index = self
+            .index(pos)
+            .ok_or_else(|| format!("{pos} is outside of the {} tile layer", self.size))?;
+        self.tiles[index] = tile;
+
+        Ok(())
+    }
+
+    /// Set every tile in the [`Area`] to the same tile, or empty them with `None`. Parts of the area outside of the layer are ignored
+    pub fn fill(&mut self, area: Area, tile: Option<TileId>) {
+        let Some(area) = area.intersection(&Area::new(Vec2D::ZERO, self.size)) else {
+            return;
+        };
+        for y in area.pos.y..area.end().y {
+            for x in area.pos.x..area.end().x {
+                let _ = self.set(Vec2D::new(x, y), tile);
+            }
+        }
+    }
+
+    /// Return the position and [`TileId`] of every tile that isn't empty, going along each row from the top
+    #[must_use]
+    pub fn tiles(&self) -> Vec<(Vec2D, TileId)> {
+        (0..self.size.y)
+            .flat_map(|y| (0..self.size.x).map(move |x| Vec2D::new(x, y)))
+            .filter_map(|pos| self.get(pos).map(|id| (pos, id)))
+            .collect()
+    }
+}
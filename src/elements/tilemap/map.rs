@@ -0,0 +1,395 @@
+use super::{Tile, TileId, TileLayer, Tileset};
+use crate::elements::{
+    view::{Area, ViewElement},
+    Pixel, Vec2D,
+};
+use std::{collections::HashMap, fs, path::Path};
+
+/// A grid of tiles made of one or more [`TileLayer`]s drawn on top of each other, with every [`TileId`] looked up in a shared [`Tileset`]. Layers are drawn in order, so later layers cover earlier ones except where their tiles have spaces
+///
+/// Large maps are usually bigger than the screen, so set a [`window`](Tilemap::window) to only draw the tiles that can be seen. `Tilemap` also implements [`Solid`](crate::physics::Solid), and [`Tilemap::solid_points()`] can be added to a [`CollisionWorld`](crate::elements::collision::CollisionWorld)
+#[derive(Debug, Clone)]
+pub struct Tilemap {
+    /// The position of the top-left corner of the map
+    pub pos: Vec2D,
+    /// The tiles that the layers' [`TileId`]s refer to
+    pub tileset: Tileset,
+    /// The layers of the map, from the bottom up
+    pub layers: Vec<TileLayer>,
+    /// The area to draw, if only part of the map is needed. Tiles outside the window are skipped entirely, and tiles on its edge are cut off
+    pub window: Option<Area>,
+}
+
+impl Tilemap {
+    /// Create a new `Tilemap` with no layers and no window
+    #[must_use]
+    pub const fn new(pos: Vec2D, tileset: Tileset) -> Self {
+        Self {
+            pos,
+            tileset,
+            layers: vec![],
+            window: None,
+        }
+    }
+
+    /// Parse a plain-text map. The map is split into sections, each starting with a `[legend]` or `[layer]` line. Each line of a legend maps a character to a [`TileId`] in the tileset, such as `# = 1`, and each following layer is drawn with those characters, one per tile. Spaces are always empty tiles, blank lines at the end of a layer are ignored, and all newlines at the beginning of the map are removed
+    /// ```
+    /// use gemini_engine::elements::{tilemap::{Tile, Tilemap, Tileset}, view::{ColChar, Modifier}, Vec2D};
+    ///
+    /// let tileset = Tileset::new(Vec2D::new(1, 1))
+    ///     .with_tile(0, Tile::new(ColChar::new('.', Modifier::None)))
+    ///     .with_tile(1, Tile::new(ColChar::SOLID).with_solid(true))
+    ///     .with_tile(2, Tile::new(ColChar::new('D', Modifier::None)).with_property("leads_to", "cellar"));
+    ///
+    /// let map = Tilemap::parse(Vec2D::ZERO, "
+    /// [legend]
+    /// . = 0
+    /// W = 1
+    /// D = 2
+    ///
+    /// [layer]
+    /// WWWWW
+    /// W...W
+    /// WWWWW
+    ///
+    /// [layer]
+    ///
+    ///    D", tileset).unwrap();
+    ///
+    /// assert_eq!(map.layers.len(), 2);
+    /// assert_eq!(map.tiles_at(Vec2D::new(3, 1)).last().unwrap().property("leads_to"), Some("cellar"));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if a legend line can't be parsed, a legend refers to a tile that isn't in the tileset, or a layer uses a character that isn't in the legend
+    pub fn parse(pos: Vec2D, map: &str, tileset: Tileset) -> Result<Self, String> {
+        let mut legend: HashMap<char, TileId> = HashMap::new();
+        let mut layers = vec![];
+        let mut section = None;
+
+        for (i, line) in map.trim_start_matches('\n').lines().enumerate() {
+            let line_number = i + 1;
+            match line.trim() {
+                "[legend]" => {
+                    section = Some(None);
+                    continue;
+                }
+                "[layer]" => {
+                    layers.push(vec![]);
+                    section = Some(Some(layers.len() - 1));
+                    continue;
+                }
+                _ => (),
+            }
+
+            match section {
+                None if line.trim().is_empty() => (),
+                None => {
+                    return Err(format!(
+                        "Line {line_number}: expected [legend] or [layer] before {line:?}"
+                    ))
+                }
+                Some(None) => {
+                    if let Some((c, id)) = parse_legend_line(line, line_number)? {
+                        if !tileset.contains(id) {
+                            return Err(format!(
+                                "Line {line_number}: tile {id} for {c:?} is not in the tileset"
+                            ));
+                        }
+                        legend.insert(c, id);
+                    }
+                }
+                Some(Some(layer)) => {
+                    let row = line
+                        .chars()
+                        .map(|c| match c {
+                            ' ' => Ok(None),
+                            c => legend.get(&c).map(|id| Some(*id)).ok_or_else(|| {
+                                format!("Line {line_number}: {c:?} is not in the legend")
+                            }),
+                        })
+                        .collect::<Result<Vec<_>, String>>()?;
+                    layers[layer].push(row);
+                }
+            }
+        }
+
+        let mut tilemap = Self::new(pos, tileset);
+        for mut rows in layers {
+            while rows.last().is_some_and(Vec::is_empty) {
+                rows.pop();
+            }
+            tilemap.layers.push(TileLayer::from_rows(rows));
+        }
+
+        Ok(tilemap)
+    }
+
+    /// Load a plain-text map from a file. See [`Tilemap::parse()`] for the format
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be read or is not a valid map
+    pub fn from_file(pos: Vec2D, path: impl AsRef<Path>, tileset: Tileset) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        Self::parse(pos, &contents, tileset)
+    }
+
+    /// Return the `Tilemap` with the layer added on top of the others
+    #[must_use]
+    pub fn with_layer(mut self, layer: TileLayer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Return the `Tilemap` with its [`window`](Tilemap::window) set to the chosen area
+    #[must_use]
+    pub const fn with_window(mut self, window: Area) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Return the width and height of the map in tiles, which is the size of its largest layer
+    #[must_use]
+    pub fn size(&self) -> Vec2D {
+        self.layers
+            .iter()
+            .map(TileLayer::size)
+            .fold(Vec2D::ZERO, |size, layer| {
+                Vec2D::new(size.x.max(layer.x), size.y.max(layer.y))
+            })
+    }
+
+    /// Return the cells covered by the whole map
+    #[must_use]
+    pub fn area(&self) -> Area {
+        Area::new(self.pos, self.size() * self.tileset.tile_size())
+    }
+
+    /// Return the position, in tiles, of the tile covering the cell
+    #[must_use]
+    pub fn tile_coords(&self, pos: Vec2D) -> Vec2D {
+        let tile_size = self.tileset.tile_size();
+        let offset = pos - self.pos;
+        Vec2D::new(
+            offset.x.div_euclid(tile_size.x),
+            offset.y.div_euclid(tile_size.y),
+        )
+    }
+
+    /// Return the cells covered by the tile at the position, in tiles
+    #[must_use]
+    pub fn tile_area(&self, coords: Vec2D) -> Area {
+        let tile_size = self.tileset.tile_size();
+        Area::new(self.pos + coords * tile_size, tile_size)
+    }
+
+    /// Return the tiles covering the cell, from the bottom layer up
+    pub fn tiles_at(&self, pos: Vec2D) -> impl Iterator<Item = &Tile> {
+        let coords = self.tile_coords(pos);
+        self.layers
+            .iter()
+            .filter_map(move |layer| layer.get(coords))
+            .filter_map(|id| self.tileset.get(id))
+    }
+
+    /// Returns true if any of the tiles covering the cell are [`solid`](Tile::solid)
+    #[must_use]
+    pub fn is_solid(&self, pos: Vec2D) -> bool {
+        self.tiles_at(pos).any(|tile| tile.solid)
+    }
+
+    /// Return the highest [`damage`](Tile::damage) of the tiles covering the cell
+    #[must_use]
+    pub fn damage_at(&self, pos: Vec2D) -> u32 {
+        self.tiles_at(pos)
+            .map(|tile| tile.damage)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the position, in tiles, of every tile with the `TileId` on any layer. Useful for finding spawn points and other markers placed on the map
+    #[must_use]
+    pub fn find(&self, id: TileId) -> Vec<Vec2D> {
+        let mut found: Vec<Vec2D> = self
+            .layers
+            .iter()
+            .flat_map(TileLayer::tiles)
+            .filter(|(_, tile)| *tile == id)
+            .map(|(coords, _)| coords)
+            .collect();
+        found.sort_unstable_by_key(|p| (p.y, p.x));
+        found.dedup();
+
+        found
+    }
+
+    /// Return every cell covered by a tile that matches the predicate, sorted by row
+    #[must_use]
+    pub fn points_where(&self, predicate: impl Fn(&Tile) -> bool) -> Vec<Vec2D> {
+        let mut coords: Vec<Vec2D> = self
+            .layers
+            .iter()
+            .flat_map(TileLayer::tiles)
+            .filter(|(_, id)| self.tileset.get(*id).is_some_and(&predicate))
+            .map(|(coords, _)| coords)
+            .collect();
+        coords.sort_unstable_by_key(|p| (p.y, p.x));
+        coords.dedup();
+
+        let tile_size = self.tileset.tile_size();
+        let mut points: Vec<Vec2D> = coords
+            .into_iter()
+            .flat_map(|coords| {
+                let area = self.tile_area(coords);
+                (0..tile_size.y)
+                    .flat_map(move |y| (0..tile_size.x).map(move |x| area.pos + Vec2D::new(x, y)))
+            })
+            .collect();
+        points.sort_unstable_by_key(|p| (p.y, p.x));
+
+        points
+    }
+
+    /// Return every cell covered by a [`solid`](Tile::solid) tile, sorted by row. These can be added to a [`CollisionWorld`](crate::elements::collision::CollisionWorld) with [`insert_points()`](crate::elements::collision::CollisionWorld::insert_points())
+    #[must_use]
+    pub fn solid_points(&self) -> Vec<Vec2D> {
+        self.points_where(|tile| tile.solid)
+    }
+
+    /// Return the pixels of the visible layers that fall inside the area, only going through the tiles the area overlaps
+    #[must_use]
+    pub fn draw_area(&self, area: Area) -> Vec<Pixel> {
+        let Some(area) = area.intersection(&self.area()) else {
+            return vec![];
+        };
+        let start = self.tile_coords(area.pos);
+        let end = self.tile_coords(area.end() - Vec2D::new(1, 1));
+        let tile_size = self.tileset.tile_size();
+
+        let mut pixels = vec![];
+        for layer in self.layers.iter().filter(|layer| layer.visible) {
+            for tile_y in start.y..=end.y {
+                for tile_x in start.x..=end.x {
+                    let coords = Vec2D::new(tile_x, tile_y);
+                    let Some(tile) = layer.get(coords).and_then(|id| self.tileset.get(id)) else {
+                        continue;
+                    };
+                    let origin = self.tile_area(coords).pos;
+                    for y in 0..tile_size.y {
+                        for x in 0..tile_size.x {
+                            let local = Vec2D::new(x, y);
+                            let pos = origin + local;
+                            if !area.contains(pos) {
+                                continue;
+                            }
+                            if let Some(fill_char) = tile.pattern.sample(local) {
+                                pixels.push(Pixel::new(pos, fill_char));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+}
+
+/// Parse a legend line such as `# = 1`, returning `None` for blank lines
+fn parse_legend_line(line: &str, line_number: usize) -> Result<Option<(char, TileId)>, String> {
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    let invalid =
+        || format!("Line {line_number}: expected a legend entry like `# = 1`, found {line:?}");
+
+    let (key, id) = line.split_once('=').ok_or_else(invalid)?;
+    let mut chars = key.trim().chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return Err(invalid());
+    };
+    let id = id.trim().parse().map_err(|_| invalid())?;
+
+    Ok(Some((c, id)))
+}
+
+impl ViewElement for Tilemap {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        self.draw_area(self.window.unwrap_or_else(|| self.area()))
+    }
+
+    fn bounding_box(&self) -> Option<Area> {
+        let area = self.area();
+        self.window
+            .map_or(Some(area), |window| area.intersection(&window))
+            .filter(|area| !area.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::view::{ColChar, Modifier};
+
+    fn level() -> Tilemap {
+        let tileset = Tileset::new(Vec2D::new(2, 2))
+            .with_tile(
+                1,
+                Tile::texture("/\\\n\\/", Modifier::None).with_solid(true),
+            )
+            .with_tile(
+                2,
+                Tile::new(ColChar::new('~', Modifier::None)).with_damage(3),
+            );
+
+        Tilemap::parse(
+            Vec2D::new(10, 5),
+            "[legend]\n# = 1\n~ = 2\n[layer]\n#  #\n#~~#\n####\n\n[layer]\n\n #",
+            tileset,
+        )
+        .expect("Map is valid")
+    }
+
+    #[test]
+    fn parse_and_query_tiles() {
+        let map = level();
+        assert_eq!(map.size(), Vec2D::new(4, 3));
+        assert_eq!(map.layers[1].size(), Vec2D::new(2, 2));
+        assert_eq!(map.area(), Area::new(Vec2D::new(10, 5), Vec2D::new(8, 6)));
+
+        assert!(map.is_solid(Vec2D::new(11, 6)));
+        assert!(!map.is_solid(Vec2D::new(12, 5)));
+        // The second layer adds a solid tile over the water
+        assert!(map.is_solid(Vec2D::new(12, 7)));
+        assert_eq!(map.damage_at(Vec2D::new(12, 7)), 3);
+        assert_eq!(map.damage_at(Vec2D::new(9, 7)), 0);
+
+        assert_eq!(map.find(2), vec![Vec2D::new(1, 1), Vec2D::new(2, 1)]);
+        assert_eq!(map.solid_points().len(), 9 * 4);
+
+        let unknown = Tilemap::parse(Vec2D::ZERO, "[legend]\n# = 1\n[layer]\n#?", level().tileset);
+        assert_eq!(
+            unknown.err().as_deref(),
+            Some("Line 4: '?' is not in the legend")
+        );
+        let missing = Tilemap::parse(Vec2D::ZERO, "[legend]\n# = 9", level().tileset);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn windowed_rendering() {
+        let map = level();
+        let full = map.active_pixels();
+        assert_eq!(full.len(), 9 * 4 + 2 * 4);
+
+        let window = Area::new(Vec2D::new(11, 6), Vec2D::new(2, 2));
+        let windowed = map.with_window(window).active_pixels();
+        assert!(windowed.iter().all(|pixel| window.contains(pixel.pos)));
+        let expected: Vec<Pixel> = full
+            .into_iter()
+            .filter(|pixel| window.contains(pixel.pos))
+            .collect();
+        assert_eq!(windowed, expected);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::elements::{
+    geometry::FillPattern,
+    view::{Modifier, Vec2D},
+};
+use std::collections::HashMap;
+
+/// The number a [`TileLayer`](super::TileLayer) uses to refer to a [`Tile`] in a [`Tileset`]
+pub type TileId = usize;
+
+/// A single kind of tile, with its appearance and the properties that decide how it behaves in the game
+#[derive(Debug, Clone)]
+pub struct Tile {
+    /// What the tile looks like. Patterns are lined up with the top-left corner of each tile, so a [`FillPattern::Texture`] the same size as the [`Tileset`]'s tiles draws a multi-cell ASCII tile, and a [`FillPattern::Solid`] fills every cell of the tile with the same [`ColChar`](crate::elements::view::ColChar)
+    pub pattern: FillPattern,
+    /// Whether the tile blocks movement. Every cell of a solid tile is solid, including any spaces in its texture
+    pub solid: bool,
+    /// How much damage the tile deals to anything touching it
+    pub damage: u32,
+    /// Any other properties of the tile, such as `"name"` or `"sound"`
+    pub properties: HashMap<String, String>,
+}
+
+impl Tile {
+    /// Create a new `Tile` that isn't solid, deals no damage and has no other properties. Anything that can be converted into a [`FillPattern`], such as a [`ColChar`](crate::elements::view::ColChar), can be used as its appearance
+    #[must_use]
+    pub fn new(pattern: impl Into<FillPattern>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            solid: false,
+            damage: 0,
+            properties: HashMap::new(),
+        }
+    }
+
+    /// Create a new `Tile` drawn with a multi-line ASCII texture. All newlines at the beginning of the texture will be removed
+    #[must_use]
+    pub fn texture(texture: &str, modifier: Modifier) -> Self {
+        Self::new(FillPattern::texture(texture, modifier))
+    }
+
+    /// Return the `Tile` with the chosen [`solid`](Tile::solid) value
+    #[must_use]
+    pub const fn with_solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
+    /// Return the `Tile` with the chosen [`damage`](Tile::damage) value
+    #[must_use]
+    pub const fn with_damage(mut self, damage: u32) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    /// Return the `Tile` with the property set to the value
+    #[must_use]
+    pub fn with_property(mut self, key: &str, value: &str) -> Self {
+        self.properties.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Return the value of the property, or `None` if the tile doesn't have it
+    #[must_use]
+    pub fn property(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).map(String::as_str)
+    }
+}
+
+/// A collection of [`Tile`]s, each with its own [`TileId`]. Every tile in a `Tileset` takes up the same number of cells
+#[derive(Debug, Clone)]
+pub struct Tileset {
+    tile_size: Vec2D,
+    tiles: HashMap<TileId, Tile>,
+}
+
+impl Tileset {
+    /// Create a new empty `Tileset` whose tiles are the given number of cells wide and tall. Sizes below 1 are raised to 1
+    #[must_use]
+    pub fn new(tile_size: Vec2D) -> Self {
+        Self {
+            tile_size: Vec2D::new(tile_size.x.max(1), tile_size.y.max(1)),
+            tiles: HashMap::new(),
+        }
+    }
+
+    /// Return the `Tileset` with the tile added under the `TileId`
+    #[must_use]
+    pub fn with_tile(mut self, id: TileId, tile: Tile) -> Self {
+        self.insert(id, tile);
+        self
+    }
+
+    /// Return the width and height of every tile in cells
+    #[must_use]
+    pub const fn tile_size(&self) -> Vec2D {
+        self.tile_size
+    }
+
+    /// Add the tile under the `TileId`, returning the tile it replaced if there was one
+    pub fn insert(&mut self, id: TileId, tile: Tile) -> Option<Tile> {
+        self.tiles.insert(id, tile)
+    }
+
+    /// Remove the tile with the `TileId`, returning it if there was one
+    pub fn remove(&mut self, id: TileId) -> Option<Tile> {
+        self.tiles.remove(&id)
+    }
+
+    /// Return the tile with the `TileId`, or `None` if there isn't one
+    #[must_use]
+    pub fn get(&self, id: TileId) -> Option<&Tile> {
+        self.tiles.get(&id)
+    }
+
+    /// Return a mutable reference to the tile with the `TileId`, or `None` if there isn't one
+    pub fn get_mut(&mut self, id: TileId) -> Option<&mut Tile> {
+        self.tiles.get_mut(&id)
+    }
+
+    /// Returns true if the `Tileset` has a tile with the `TileId`
+    #[must_use]
+    pub fn contains(&self, id: TileId) -> bool {
+        self.tiles.contains_key(&id)
+    }
+}
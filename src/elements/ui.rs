@@ -0,0 +1,45 @@
+//! This module holds user interface elements, such as frames, borders, layouts, interactive widgets, progress indicators, charts, tables, consoles and dialogue boxes, for building menus, panels and other screens out of text
+
+mod frame;
+pub use frame::{BorderChars, BorderStyle, Divider, Frame, FrameGroup};
+
+mod layout;
+pub use layout::{Constraint, Direction, Layout};
+
+mod input;
+pub use input::{Key, KeyEvent};
+
+mod widget;
+pub use widget::{FocusGroup, Widget, WidgetEvent, WidgetStyle};
+
+mod button;
+pub use button::{Button, Checkbox};
+
+mod list;
+pub use list::{List, RadioGroup};
+
+mod tabs;
+pub use tabs::Tabs;
+
+mod progress;
+pub use progress::{Gauge, ProgressBar, Spinner, SpinnerStyle};
+
+mod chart;
+pub use chart::{BarChart, BarGroup, Sparkline};
+
+mod plot;
+pub use plot::{LineChart, Marker, Series, SeriesStyle};
+
+mod table;
+pub use table::{Cell, CellOverflow, ColumnWidth, Table};
+
+mod text_input;
+pub use text_input::TextInput;
+
+mod console;
+#[cfg(feature = "log")]
+pub use console::ConsoleLogger;
+pub use console::{Console, LogLevel, LogLine};
+
+mod dialogue;
+pub use dialogue::DialogueBox;
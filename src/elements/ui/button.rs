@@ -0,0 +1,127 @@
+use super::{widget::draw_line, Key, KeyEvent, Widget, WidgetEvent, WidgetStyle};
+use crate::elements::{view::ViewElement, Pixel, Vec2D};
+
+/// A button drawn as `[ label ]`, which reports [`WidgetEvent::Pressed`] when enter or space is pressed while it is focused
+#[derive(Debug, Clone)]
+pub struct Button {
+    /// The position of the button's left edge
+    pub pos: Vec2D,
+    /// The text displayed on the button
+    pub label: String,
+    /// The modifiers used to draw the button. The highlight modifier is used while the button is focused
+    pub style: WidgetStyle,
+    focused: bool,
+}
+
+impl Button {
+    /// Create a new `Button` with the default [`WidgetStyle`]
+    #[must_use]
+    pub fn new(pos: Vec2D, label: &str) -> Self {
+        Self {
+            pos,
+            label: String::from(label),
+            style: WidgetStyle::default(),
+            focused: false,
+        }
+    }
+
+    /// Return the `Button` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl ViewElement for Button {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        draw_line(
+            self.pos,
+            &format!("[ {} ]", self.label),
+            self.style.pick(self.focused),
+        )
+    }
+}
+
+impl Widget for Button {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        match key.key {
+            Key::Enter | Key::Char(' ') => Some(WidgetEvent::Pressed),
+            _ => None,
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+/// A checkbox drawn as `[x] label` or `[ ] label`, which is toggled by pressing enter or space while it is focused
+#[derive(Debug, Clone)]
+pub struct Checkbox {
+    /// The position of the checkbox's left edge
+    pub pos: Vec2D,
+    /// The text displayed after the box
+    pub label: String,
+    /// Whether the box is checked
+    pub checked: bool,
+    /// The modifiers used to draw the checkbox. The highlight modifier is used while the checkbox is focused
+    pub style: WidgetStyle,
+    focused: bool,
+}
+
+impl Checkbox {
+    /// Create a new `Checkbox` with the default [`WidgetStyle`]
+    #[must_use]
+    pub fn new(pos: Vec2D, label: &str, checked: bool) -> Self {
+        Self {
+            pos,
+            label: String::from(label),
+            checked,
+            style: WidgetStyle::default(),
+            focused: false,
+        }
+    }
+
+    /// Return the `Checkbox` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl ViewElement for Checkbox {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let mark = if self.checked { 'x' } else { ' ' };
+        draw_line(
+            self.pos,
+            &format!("[{mark}] {}", self.label),
+            self.style.pick(self.focused),
+        )
+    }
+}
+
+impl Widget for Checkbox {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        match key.key {
+            Key::Enter | Key::Char(' ') => {
+                self.checked = !self.checked;
+                Some(WidgetEvent::Toggled(self.checked))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
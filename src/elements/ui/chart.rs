@@ -0,0 +1,308 @@
+use super::{widget::draw_line, Direction, ProgressBar};
+use crate::elements::{
+    view::{Area, ColChar, Colour, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+
+/// Return the given maximum, or the largest value in the data if `None`. The result is always above zero so that it can be divided by
+pub(super) fn auto_max(max: Option<f64>, values: impl Iterator<Item = f64>) -> f64 {
+    let max = max.unwrap_or_else(|| values.fold(0.0, f64::max));
+    if max > 0.0 {
+        max
+    } else {
+        1.0
+    }
+}
+
+/// A compact chart showing a series of values as a row of vertical bars, one cell wide each, drawn with partial block characters (`▁▂▃▄▅▆▇█`) for extra precision
+/// ```
+/// use gemini_engine::elements::{ui::Sparkline, view::ViewElement, Vec2D};
+///
+/// let sparkline = Sparkline::new(Vec2D::ZERO, vec![1.0, 2.0, 4.0, 8.0]);
+/// let chars: String = sparkline.active_pixels().iter().map(|p| p.fill_char.text_char).collect();
+///
+/// assert_eq!(chars, "▁▂▄█");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sparkline {
+    /// The position of the top-left corner of the sparkline
+    pub pos: Vec2D,
+    /// The values to display, from left to right. Negative values are treated as zero
+    pub data: Vec<f64>,
+    /// The height of the sparkline in cells
+    pub height: isize,
+    /// The value that reaches the top of the sparkline. If `None`, the largest value in the data is used
+    pub max: Option<f64>,
+    /// The [`ColChar`] used for filled cells. Its modifier is also used for partially filled cells
+    pub fill: ColChar,
+}
+
+impl Sparkline {
+    /// Create a new `Sparkline` one cell tall, scaled to the largest value in the data
+    #[must_use]
+    pub const fn new(pos: Vec2D, data: Vec<f64>) -> Self {
+        Self {
+            pos,
+            data,
+            height: 1,
+            max: None,
+            fill: ColChar::SOLID,
+        }
+    }
+
+    /// Return the `Sparkline` with the chosen height
+    #[must_use]
+    pub const fn with_height(mut self, height: isize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Return the `Sparkline` with the chosen maximum value
+    #[must_use]
+    pub const fn with_max(mut self, max: Option<f64>) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Return the `Sparkline` with the chosen fill
+    #[must_use]
+    pub const fn with_fill(mut self, fill: ColChar) -> Self {
+        self.fill = fill;
+        self
+    }
+}
+
+impl ViewElement for Sparkline {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let max = auto_max(self.max, self.data.iter().copied());
+
+        (0..)
+            .zip(&self.data)
+            .flat_map(|(x, value)| {
+                ProgressBar::new(
+                    self.pos + Vec2D::new(x, 0),
+                    self.height,
+                    Direction::Vertical,
+                    value / max,
+                )
+                .with_fill(self.fill)
+                .active_pixels()
+            })
+            .collect()
+    }
+}
+
+/// A labelled group of bars in a [`BarChart`], one bar for each series
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarGroup {
+    /// The label drawn below (or, for horizontal charts, beside) the group
+    pub label: String,
+    /// The value of each bar in the group
+    pub values: Vec<f64>,
+}
+
+impl BarGroup {
+    /// Create a new `BarGroup` from a label and the values of its bars
+    #[must_use]
+    pub fn new(label: &str, values: Vec<f64>) -> Self {
+        Self {
+            label: String::from(label),
+            values,
+        }
+    }
+}
+
+/// A chart of groups of bars, drawn with sub-cell precision. With [`Direction::Vertical`] the bars grow upwards with the group labels along the bottom, and with [`Direction::Horizontal`] they grow to the right with the labels on the left. The bars of each group are coloured by their position in the group using [`BarChart::colours`], so that each series has its own colour
+/// ```
+/// use gemini_engine::elements::{ui::{BarChart, BarGroup, Direction}, view::Colour, Vec2D};
+///
+/// let chart = BarChart::new(Vec2D::ZERO, Vec2D::new(20, 8), Direction::Vertical, vec![
+///     BarGroup::new("Mon", vec![3.0, 5.0]),
+///     BarGroup::new("Tue", vec![4.5, 2.0]),
+/// ])
+/// .with_colours(vec![Colour::rgb(255, 0, 0), Colour::rgb(0, 0, 255)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BarChart {
+    /// The position of the top-left corner of the chart
+    pub pos: Vec2D,
+    /// The size of the chart, including the labels
+    pub size: Vec2D,
+    /// The direction the bars are laid out in
+    pub direction: Direction,
+    /// The groups of bars
+    pub groups: Vec<BarGroup>,
+    /// The colour of each series. If there are more series than colours, the colours are repeated. If empty, the bars are left uncoloured
+    pub colours: Vec<Colour>,
+    /// The thickness of each bar in cells
+    pub bar_width: isize,
+    /// The space between each group of bars
+    pub group_gap: isize,
+    /// The value that fills a bar completely. If `None`, the largest value in the chart is used
+    pub max: Option<f64>,
+}
+
+impl BarChart {
+    /// Create a new `BarChart` with one cell wide bars, a gap of one cell between groups and no colours
+    #[must_use]
+    pub const fn new(pos: Vec2D, size: Vec2D, direction: Direction, groups: Vec<BarGroup>) -> Self {
+        Self {
+            pos,
+            size,
+            direction,
+            groups,
+            colours: vec![],
+            bar_width: 1,
+            group_gap: 1,
+            max: None,
+        }
+    }
+
+    /// Return the `BarChart` with the chosen series colours
+    #[must_use]
+    pub fn with_colours(mut self, colours: Vec<Colour>) -> Self {
+        self.colours = colours;
+        self
+    }
+
+    /// Return the `BarChart` with the chosen bar width
+    #[must_use]
+    pub const fn with_bar_width(mut self, bar_width: isize) -> Self {
+        self.bar_width = bar_width;
+        self
+    }
+
+    /// Return the `BarChart` with the chosen gap between groups
+    #[must_use]
+    pub const fn with_group_gap(mut self, group_gap: isize) -> Self {
+        self.group_gap = group_gap;
+        self
+    }
+
+    /// Return the `BarChart` with the chosen maximum value
+    #[must_use]
+    pub const fn with_max(mut self, max: Option<f64>) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Return the [`ColChar`] used for the series at the index
+    fn series_fill(&self, series: usize) -> ColChar {
+        if self.colours.is_empty() {
+            ColChar::SOLID
+        } else {
+            ColChar::SOLID.with_colour(self.colours[series % self.colours.len()])
+        }
+    }
+}
+
+impl ViewElement for BarChart {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let max = auto_max(
+            self.max,
+            self.groups
+                .iter()
+                .flat_map(|group| group.values.iter().copied()),
+        );
+        let has_labels = self.groups.iter().any(|group| !group.label.is_empty());
+        let label_width = match self.direction {
+            Direction::Horizontal if has_labels => {
+                self.groups
+                    .iter()
+                    .map(|group| group.label.chars().count())
+                    .max()
+                    .unwrap_or(0) as isize
+                    + 1
+            }
+            Direction::Vertical if has_labels => 1,
+            _ => 0,
+        };
+        let (bar_length, cross_size) = match self.direction {
+            Direction::Horizontal => (self.size.x - label_width, self.size.y),
+            Direction::Vertical => (self.size.y - label_width, self.size.x),
+        };
+
+        let mut pixels = vec![];
+        let mut offset = 0;
+        // Groups that start past the edge of the chart are skipped, and anything else sticking out is cut off below
+        for group in &self.groups {
+            if offset >= cross_size {
+                break;
+            }
+            let group_width = group.values.len() as isize * self.bar_width;
+
+            for (i, value) in (0..).zip(&group.values) {
+                let bar_offset = offset + i * self.bar_width;
+                if bar_offset >= cross_size {
+                    break;
+                }
+                let bar_pos = match self.direction {
+                    Direction::Horizontal => Vec2D::new(label_width, bar_offset),
+                    Direction::Vertical => Vec2D::new(bar_offset, 0),
+                };
+                let bar =
+                    ProgressBar::new(self.pos + bar_pos, bar_length, self.direction, value / max)
+                        .with_thickness(self.bar_width)
+                        .with_fill(self.series_fill(i as usize));
+                pixels.extend(bar.active_pixels());
+            }
+
+            let (label, label_pos): (String, Vec2D) = match self.direction {
+                Direction::Horizontal => (group.label.clone(), Vec2D::new(0, offset)),
+                Direction::Vertical => {
+                    let label: String = group
+                        .label
+                        .chars()
+                        .take(group_width.max(0) as usize)
+                        .collect();
+                    let centre = (group_width - label.chars().count() as isize) / 2;
+                    (label, Vec2D::new(offset + centre, bar_length))
+                }
+            };
+            pixels.extend(
+                draw_line(self.pos + label_pos, &label, Modifier::None)
+                    .into_iter()
+                    .filter(|pixel| pixel.fill_char.text_char != ' '),
+            );
+
+            offset += group_width + self.group_gap;
+        }
+
+        let area = Area::new(self.pos, self.size);
+        pixels.retain(|pixel| area.contains(pixel.pos));
+
+        pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::view::utils;
+
+    #[test]
+    fn grouped_vertical_bars() {
+        let chart = BarChart::new(
+            Vec2D::ZERO,
+            Vec2D::new(5, 3),
+            Direction::Vertical,
+            vec![
+                BarGroup::new("a", vec![4.0, 2.0]),
+                BarGroup::new("b", vec![1.0]),
+            ],
+        );
+
+        let rows = utils::render_rows(&chart.active_pixels(), chart.size, ' ');
+        assert_eq!(rows, ["█    ", "██ ▄ ", "a  b "]);
+
+        // Bars past the edge of the chart are cut off
+        let narrow = BarChart {
+            size: Vec2D::new(2, 3),
+            ..chart
+        };
+        assert!(narrow
+            .active_pixels()
+            .iter()
+            .all(|pixel| pixel.pos.x < 2 && pixel.pos.y < 3));
+    }
+}
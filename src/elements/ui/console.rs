@@ -0,0 +1,405 @@
+use super::{widget::draw_line, Key, KeyEvent, Widget, WidgetEvent};
+use crate::elements::{
+    ascii::TextWrap,
+    view::{ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+use std::collections::VecDeque;
+
+/// How important a [`LogLine`] is, which decides its colour in the [`Console`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Very detailed information, usually only useful when tracking down a specific problem
+    Trace,
+    /// Information useful for debugging
+    Debug,
+    /// General information
+    Info,
+    /// Something unexpected that isn't an error
+    Warn,
+    /// Something went wrong
+    Error,
+}
+
+impl LogLevel {
+    /// The tag shown before lines of this level, such as `INFO`
+    #[must_use]
+    pub const fn tag(&self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    /// The [`Modifier`] used to draw the tag of lines of this level
+    #[must_use]
+    pub const fn modifier(&self) -> Modifier {
+        match self {
+            Self::Trace => Modifier::Coded(2),
+            Self::Debug => Modifier::BLUE,
+            Self::Info => Modifier::GREEN,
+            Self::Warn => Modifier::YELLOW,
+            Self::Error => Modifier::RED,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Trace => Self::Trace,
+            log::Level::Debug => Self::Debug,
+            log::Level::Info => Self::Info,
+            log::Level::Warn => Self::Warn,
+            log::Level::Error => Self::Error,
+        }
+    }
+}
+
+/// A single message in a [`Console`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// The level of the message
+    pub level: LogLevel,
+    /// The text of the message
+    pub message: String,
+}
+
+impl LogLine {
+    /// Create a new `LogLine`
+    #[must_use]
+    pub fn new(level: LogLevel, message: &str) -> Self {
+        Self {
+            level,
+            message: String::from(message),
+        }
+    }
+
+    /// Split a message into one `LogLine` per line. An empty message still makes one empty line, so that it isn't lost
+    fn from_message(level: LogLevel, message: &str) -> Vec<Self> {
+        if message.is_empty() {
+            return vec![Self::new(level, "")];
+        }
+        message.lines().map(|line| Self::new(level, line)).collect()
+    }
+}
+
+/// A scrollback console that collects log messages and shows the most recent ones in a rectangle, wrapping long messages onto more lines. It's drawn with an opaque background so it can be blit on top of your game as an overlay, and can be shown and hidden with [`Console::toggle()`].
+///
+/// As a [`Widget`], the up and down arrows, page up and page down scroll through older messages and end jumps back to the newest ones. With the `log` feature enabled, [`ConsoleLogger`] lets you send messages from the `log` crate's macros to a console instead of printing them over your rendered frames
+/// ```
+/// use gemini_engine::elements::{ui::{Console, LogLevel}, Vec2D};
+///
+/// let mut console = Console::new(Vec2D::ZERO, Vec2D::new(40, 8));
+///
+/// console.push(LogLevel::Info, "Loaded level 1");
+/// console.push(LogLevel::Warn, "Player spawned inside a wall");
+///
+/// assert_eq!(console.lines().len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Console {
+    /// The position of the top-left corner of the console
+    pub pos: Vec2D,
+    /// The size of the console
+    pub size: Vec2D,
+    /// The most lines the console will hold before dropping the oldest ones
+    pub capacity: usize,
+    /// Whether the console is drawn at all
+    pub visible: bool,
+    /// Whether each line starts with its level's tag
+    pub show_level: bool,
+    /// The [`ColChar`] filling the console's background
+    pub background: ColChar,
+    /// A raw [`Modifier`], determining the appearance of the messages
+    pub modifier: Modifier,
+    lines: VecDeque<LogLine>,
+    scroll: usize,
+    focused: bool,
+}
+
+impl Console {
+    /// Create a new, visible `Console` holding up to 1000 lines, with level tags shown
+    #[must_use]
+    pub const fn new(pos: Vec2D, size: Vec2D) -> Self {
+        Self {
+            pos,
+            size,
+            capacity: 1000,
+            visible: true,
+            show_level: true,
+            background: ColChar::new(' ', Modifier::None),
+            modifier: Modifier::None,
+            lines: VecDeque::new(),
+            scroll: 0,
+            focused: false,
+        }
+    }
+
+    /// Return the `Console` with the chosen capacity
+    #[must_use]
+    pub const fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Return the `Console` with the chosen background
+    #[must_use]
+    pub const fn with_background(mut self, background: ColChar) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Add a message to the console. Messages with several lines are split into one [`LogLine`] per line
+    pub fn push(&mut self, level: LogLevel, message: &str) {
+        for line in LogLine::from_message(level, message) {
+            self.push_line(line);
+        }
+    }
+
+    /// Add a single [`LogLine`] to the console, dropping the oldest line if the console is full. If the console is scrolled up, it stays on the same messages
+    pub fn push_line(&mut self, line: LogLine) {
+        self.lines.push_back(line);
+        while self.lines.len() > self.capacity {
+            self.lines.pop_front();
+        }
+        if self.scroll > 0 {
+            self.scroll = (self.scroll + 1).min(self.lines.len().saturating_sub(1));
+        }
+    }
+
+    /// Move any messages sent through [`ConsoleLogger`] into the console. Call this every frame before blitting the console
+    #[cfg(feature = "log")]
+    pub fn collect_logs(&mut self) {
+        for line in ConsoleLogger::take_lines() {
+            self.push_line(line);
+        }
+    }
+
+    /// Remove every line from the console
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.scroll = 0;
+    }
+
+    /// The lines in the console, from oldest to newest
+    #[must_use]
+    pub const fn lines(&self) -> &VecDeque<LogLine> {
+        &self.lines
+    }
+
+    /// Show the console if it is hidden, or hide it if it is shown
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Scroll back through older lines, or forward with a negative number
+    pub fn scroll_by(&mut self, lines: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(lines)
+            .min(self.lines.len().saturating_sub(1));
+    }
+
+    /// Jump back to the newest lines
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Return the rows to draw from top to bottom, along with the level of any row that starts with a level tag
+    fn rows(&self) -> Vec<(Option<LogLevel>, String)> {
+        let width = self.size.x.max(1) as usize;
+        let height = self.size.y.max(0) as usize;
+
+        let mut rows = vec![];
+        for line in self.lines.iter().rev().skip(self.scroll) {
+            if rows.len() >= height {
+                break;
+            }
+            let text = if self.show_level {
+                format!("{:5} {}", line.level.tag(), line.message)
+            } else {
+                line.message.clone()
+            };
+            let wrapped = TextWrap::Character.wrap_lines(&text, width);
+            for (i, row) in wrapped.into_iter().enumerate().rev() {
+                let level = (self.show_level && i == 0).then_some(line.level);
+                rows.push((level, row));
+            }
+        }
+
+        rows.truncate(height);
+        rows.reverse();
+        rows
+    }
+}
+
+impl ViewElement for Console {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        if !self.visible {
+            return vec![];
+        }
+
+        let width = self.size.x.max(0) as usize;
+        let rows = self.rows();
+        let mut pixels = vec![];
+        for y in 0..self.size.y {
+            let (level, row) = rows.get(y as usize).cloned().unwrap_or_default();
+            let mut row_pixels = draw_line(
+                self.pos + Vec2D::new(0, y),
+                &format!("{row:width$}"),
+                self.modifier,
+            );
+            if let Some(level) = level {
+                for pixel in row_pixels.iter_mut().take(level.tag().len()) {
+                    pixel.fill_char.modifier = level.modifier();
+                }
+            }
+            for pixel in &mut row_pixels {
+                if pixel.fill_char.text_char == ' ' {
+                    pixel.fill_char = self.background;
+                }
+            }
+            pixels.extend(row_pixels);
+        }
+
+        pixels
+    }
+}
+
+impl Widget for Console {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        let page = self.size.y.max(1);
+        match key.key {
+            Key::Up => self.scroll_by(1),
+            Key::Down => self.scroll_by(-1),
+            Key::PageUp => self.scroll_by(page),
+            Key::PageDown => self.scroll_by(-page),
+            Key::End => self.scroll_to_bottom(),
+            _ => (),
+        }
+
+        None
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(feature = "log")]
+static LOGGED_LINES: std::sync::Mutex<Vec<LogLine>> = std::sync::Mutex::new(Vec::new());
+
+/// A logger for the `log` crate which collects messages for a [`Console`] instead of printing them, so that they don't get in the way of your rendered frames. Install it once at the start of your program with [`ConsoleLogger::init()`], then call [`Console::collect_logs()`] every frame
+/// ```
+/// use gemini_engine::elements::{ui::{Console, ConsoleLogger}, Vec2D};
+///
+/// ConsoleLogger::init(log::LevelFilter::Info).expect("Another logger was already set");
+/// let mut console = Console::new(Vec2D::ZERO, Vec2D::new(40, 8));
+///
+/// log::info!("Hello from the log crate");
+/// console.collect_logs();
+///
+/// assert_eq!(console.lines()[0].message, "Hello from the log crate");
+/// ```
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy)]
+pub struct ConsoleLogger;
+
+#[cfg(feature = "log")]
+impl ConsoleLogger {
+    /// Install the `ConsoleLogger` as the `log` crate's logger, only recording messages at or above the given level
+    ///
+    /// # Errors
+    /// Returns an error if a logger has already been set
+    pub fn init(level: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+        log::set_logger(&Self)?;
+        log::set_max_level(level);
+        Ok(())
+    }
+
+    /// Remove and return every line logged since the last call
+    fn take_lines() -> Vec<LogLine> {
+        LOGGED_LINES
+            .lock()
+            .map(|mut lines| std::mem::take(&mut *lines))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if let Ok(mut lines) = LOGGED_LINES.lock() {
+            let message = record.args().to_string();
+            lines.extend(LogLine::from_message(record.level().into(), &message));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::view::utils;
+
+    fn render(console: &Console) -> Vec<String> {
+        utils::render_rows(&console.active_pixels(), console.size, '.')
+    }
+
+    #[test]
+    fn wraps_and_scrolls() {
+        let mut console = Console::new(Vec2D::ZERO, Vec2D::new(10, 3));
+        console.show_level = false;
+        console.push(LogLevel::Info, "first");
+        console.push(LogLevel::Info, "a long second line");
+        assert_eq!(render(&console), ["first     ", "a long sec", "ond line  "]);
+
+        console.scroll_by(1);
+        console.push(LogLevel::Error, "third");
+        assert_eq!(render(&console)[..2], ["first     ", "          "]);
+
+        console.toggle();
+        assert!(console.active_pixels().is_empty());
+    }
+
+    #[test]
+    fn scroll_stays_in_range_when_full() {
+        let mut console = Console::new(Vec2D::ZERO, Vec2D::new(5, 3)).with_capacity(3);
+        console.show_level = false;
+        for line in ["a", "b", "c"] {
+            console.push(LogLevel::Info, line);
+        }
+        console.scroll_by(2);
+        console.push(LogLevel::Info, "d");
+        console.push(LogLevel::Info, "e");
+
+        assert_eq!(console.scroll, 2);
+        assert_eq!(render(&console)[0], "c    ");
+    }
+
+    #[test]
+    fn empty_messages_are_kept() {
+        let mut console = Console::new(Vec2D::ZERO, Vec2D::new(5, 3));
+        console.push(LogLevel::Info, "");
+        console.push(LogLevel::Info, "a\nb");
+        assert_eq!(console.lines().len(), 3);
+        assert_eq!(console.lines()[0].message, "");
+    }
+}
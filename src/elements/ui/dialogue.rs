@@ -0,0 +1,322 @@
+use super::{BorderStyle, Frame};
+use crate::elements::{
+    ascii::{parse_markup, StyledSpan, TextAlign, TextWrap},
+    view::{ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+use std::time::Duration;
+
+/// A page of text in a [`DialogueBox`], made up of lines of characters along with their index in the full text
+type Page = Vec<Vec<(usize, char)>>;
+
+/// An RPG-style dialogue box, which reveals its text a character at a time like a typewriter. Text that doesn't fit in the box is split into pages, and a continue indicator is shown in the bottom-right corner once a page has been fully revealed. The speaker's name is shown in the top edge of the border.
+///
+/// Call [`DialogueBox::update()`] every frame with the time since the last one, and [`DialogueBox::advance()`] when the player presses a button to continue
+/// ```
+/// use gemini_engine::{elements::{ui::DialogueBox, Vec2D}, gameloop::Duration};
+///
+/// let mut dialogue = DialogueBox::from_markup(
+///     Vec2D::ZERO,
+///     Vec2D::new(30, 5),
+///     "Welcome, traveller! The [yellow]golden key[/] is hidden in the old mill.",
+/// )
+/// .expect("Markup should be valid")
+/// .with_speaker("Old Man");
+///
+/// dialogue.update(Duration::from_millis(100));
+/// assert!(!dialogue.is_page_complete());
+///
+/// // The first press reveals the rest of the page, the second goes to the next page
+/// dialogue.advance();
+/// assert!(dialogue.is_page_complete());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DialogueBox {
+    /// The position of the top-left corner of the box
+    pub pos: Vec2D,
+    /// The name shown in the top edge of the border
+    pub speaker: Option<String>,
+    /// The style of the border
+    pub border: BorderStyle,
+    /// A raw [`Modifier`], determining the appearance of the border and any text not covered by a span
+    pub modifier: Modifier,
+    /// The [`ColChar`] filling the inside of the box
+    pub background: ColChar,
+    /// How many characters are revealed per second
+    pub chars_per_second: f64,
+    /// How long to pause after the end of a sentence (`.`, `!` or `?`). Pauses after `,`, `;` and `:` are half as long
+    pub punctuation_pause: Duration,
+    /// The character shown in the bottom-right corner when the page is fully revealed and there are more pages to come
+    pub indicator: char,
+    size: Vec2D,
+    text: String,
+    spans: Vec<StyledSpan>,
+    pages: Vec<Page>,
+    page: usize,
+    elapsed: Duration,
+}
+
+impl DialogueBox {
+    /// Create a new `DialogueBox` revealing 30 characters per second, with a rounded border and no speaker
+    #[must_use]
+    pub fn new(pos: Vec2D, size: Vec2D, text: &str) -> Self {
+        Self {
+            pos,
+            speaker: None,
+            border: BorderStyle::Rounded,
+            modifier: Modifier::None,
+            background: ColChar::new(' ', Modifier::None),
+            chars_per_second: 30.0,
+            punctuation_pause: Duration::from_millis(300),
+            indicator: '▼',
+            size,
+            text: String::from(text),
+            spans: vec![],
+            pages: Self::wrap_pages(text, size),
+            page: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Create a new `DialogueBox` from text containing style markup. See [`parse_markup()`] for the syntax
+    ///
+    /// # Errors
+    /// Returns an error if the markup is invalid
+    pub fn from_markup(pos: Vec2D, size: Vec2D, markup: &str) -> Result<Self, String> {
+        let (text, spans) = parse_markup(markup)?;
+        let mut dialogue = Self::new(pos, size, &text);
+        dialogue.spans = spans;
+        Ok(dialogue)
+    }
+
+    /// Return the `DialogueBox` with the chosen speaker
+    #[must_use]
+    pub fn with_speaker(mut self, speaker: &str) -> Self {
+        self.speaker = Some(String::from(speaker));
+        self
+    }
+
+    /// Return the `DialogueBox` with the chosen reveal speed, in characters per second
+    #[must_use]
+    pub const fn with_speed(mut self, chars_per_second: f64) -> Self {
+        self.chars_per_second = chars_per_second;
+        self
+    }
+
+    /// Return the `DialogueBox` with the chosen pause after punctuation
+    #[must_use]
+    pub const fn with_punctuation_pause(mut self, punctuation_pause: Duration) -> Self {
+        self.punctuation_pause = punctuation_pause;
+        self
+    }
+
+    /// Return the `DialogueBox` with the chosen border style
+    #[must_use]
+    pub const fn with_border(mut self, border: BorderStyle) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// The full text of the dialogue, without any markup
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The size of the box, including its border
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        self.size
+    }
+
+    /// Resize the box, splitting the text into pages again to fit the new size. The current page is kept if there are still enough pages
+    pub fn set_size(&mut self, size: Vec2D) {
+        self.size = size;
+        self.pages = Self::wrap_pages(&self.text, size);
+        self.page = self.page.min(self.pages.len().saturating_sub(1));
+    }
+
+    /// Split the text into pages that fit inside the border of a box of the given size
+    fn wrap_pages(text: &str, size: Vec2D) -> Vec<Page> {
+        let inner_size = size - Vec2D::new(2, 2);
+        let lines: Vec<Vec<(usize, char)>> = TextWrap::Word
+            .wrap_indexed(text, inner_size.x.max(1) as usize)
+            .into_iter()
+            .map(|line| line.chars)
+            .collect();
+
+        lines
+            .chunks(inner_size.y.max(1) as usize)
+            .map(<[Vec<(usize, char)>]>::to_vec)
+            .collect()
+    }
+
+    /// The index of the current page
+    #[must_use]
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// The number of pages the text is split into
+    #[must_use]
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Advance the typewriter by the time that has passed since the last update
+    pub fn update(&mut self, delta: Duration) {
+        self.elapsed = self.elapsed.saturating_add(delta);
+    }
+
+    /// How long it takes to reveal the character, including any pause after it
+    fn char_duration(&self, c: char) -> Duration {
+        let reveal = Duration::from_secs_f64(1.0 / self.chars_per_second.max(f64::EPSILON));
+        match c {
+            '.' | '!' | '?' => reveal + self.punctuation_pause,
+            ',' | ';' | ':' => reveal + self.punctuation_pause / 2,
+            _ => reveal,
+        }
+    }
+
+    /// The number of characters revealed so far on the current page
+    #[must_use]
+    pub fn revealed_count(&self) -> usize {
+        let Some(page) = self.pages.get(self.page) else {
+            return 0;
+        };
+
+        let mut time = Duration::ZERO;
+        let mut count = 0;
+        for (_, c) in page.iter().flatten() {
+            // A character appears at the start of its duration, and any pause comes after it
+            if time > self.elapsed {
+                break;
+            }
+            time += self.char_duration(*c);
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns true if every character on the current page has been revealed
+    #[must_use]
+    pub fn is_page_complete(&self) -> bool {
+        let page_length = self
+            .pages
+            .get(self.page)
+            .map_or(0, |page| page.iter().map(Vec::len).sum());
+        self.revealed_count() >= page_length
+    }
+
+    /// Returns true if the last page has been fully revealed
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.page + 1 >= self.page_count() && self.is_page_complete()
+    }
+
+    /// Reveal the rest of the current page immediately
+    pub fn skip(&mut self) {
+        self.elapsed = Duration::MAX;
+    }
+
+    /// Respond to the player pressing a button to continue: if the page is still being revealed, the rest of it is revealed immediately, otherwise the next page starts. Returns false if the dialogue was already finished
+    pub fn advance(&mut self) -> bool {
+        if !self.is_page_complete() {
+            self.skip();
+        } else if self.page + 1 < self.page_count() {
+            self.page += 1;
+            self.elapsed = Duration::ZERO;
+        } else {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl ViewElement for DialogueBox {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let mut frame = Frame::new(self.pos, self.size, self.border, self.modifier);
+        if let Some(speaker) = &self.speaker {
+            frame = frame.with_title(speaker, TextAlign::Begin);
+        }
+
+        let mut pixels = frame.active_pixels();
+        let inner_pos = frame.inner_pos();
+        let inner_size = frame.inner_size();
+        for y in 0..inner_size.y {
+            for x in 0..inner_size.x {
+                pixels.push(Pixel::new(inner_pos + Vec2D::new(x, y), self.background));
+            }
+        }
+
+        let page = self.pages.get(self.page).map_or(&[][..], Vec::as_slice);
+        let mut remaining = self.revealed_count();
+        for (y, line) in (0..).zip(page) {
+            for (x, &(index, c)) in (0..).zip(line) {
+                if remaining == 0 {
+                    break;
+                }
+                remaining -= 1;
+                if c != ' ' {
+                    let modifier = StyledSpan::modifier_at(&self.spans, index, self.modifier);
+                    pixels.push(Pixel::new(
+                        inner_pos + Vec2D::new(x, y),
+                        ColChar::new(c, modifier),
+                    ));
+                }
+            }
+        }
+
+        if self.is_page_complete() && self.page + 1 < self.page_count() {
+            pixels.push(Pixel::new(
+                self.pos + self.size - Vec2D::new(2, 1),
+                ColChar::new(self.indicator, self.modifier),
+            ));
+        }
+
+        pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_and_paging() {
+        let mut dialogue = DialogueBox::new(Vec2D::ZERO, Vec2D::new(8, 3), "Hi. Bye")
+            .with_speed(10.0)
+            .with_punctuation_pause(Duration::from_millis(500));
+        assert_eq!(dialogue.page_count(), 2);
+
+        // "H" at 0ms, "i" at 100ms, "." at 200ms, then a 500ms pause after the full stop
+        dialogue.update(Duration::from_millis(250));
+        assert_eq!(dialogue.revealed_count(), 3);
+        dialogue.update(Duration::from_millis(300));
+        assert_eq!(dialogue.revealed_count(), 3);
+        assert!(dialogue.is_page_complete());
+
+        assert!(dialogue.advance());
+        assert_eq!(dialogue.page(), 1);
+        assert_eq!(dialogue.revealed_count(), 1);
+
+        assert!(dialogue.advance());
+        assert!(dialogue.is_finished());
+        assert!(!dialogue.advance());
+    }
+
+    #[test]
+    fn resizing_splits_pages_again() {
+        let mut dialogue = DialogueBox::new(Vec2D::ZERO, Vec2D::new(8, 3), "Hi. Bye");
+        assert_eq!(dialogue.page_count(), 2);
+        dialogue.advance();
+        dialogue.advance();
+        assert_eq!(dialogue.page(), 1);
+
+        dialogue.set_size(Vec2D::new(10, 3));
+        assert_eq!(dialogue.page_count(), 1);
+        assert_eq!(dialogue.page(), 0);
+    }
+}
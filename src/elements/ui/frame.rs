@@ -0,0 +1,408 @@
+use crate::elements::{
+    ascii::TextAlign,
+    view::{ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+use std::collections::HashMap;
+
+const UP: u8 = 0b0001;
+const DOWN: u8 = 0b0010;
+const LEFT: u8 = 0b0100;
+const RIGHT: u8 = 0b1000;
+
+/// The characters used to draw a [`Frame`]'s border, including the junctions used where borders and dividers meet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderChars {
+    /// A horizontal line, e.g. `─`
+    pub horizontal: char,
+    /// A vertical line, e.g. `│`
+    pub vertical: char,
+    /// The top left corner, e.g. `┌`
+    pub top_left: char,
+    /// The top right corner, e.g. `┐`
+    pub top_right: char,
+    /// The bottom left corner, e.g. `└`
+    pub bottom_left: char,
+    /// The bottom right corner, e.g. `┘`
+    pub bottom_right: char,
+    /// A horizontal line with a branch going down, e.g. `┬`
+    pub tee_down: char,
+    /// A horizontal line with a branch going up, e.g. `┴`
+    pub tee_up: char,
+    /// A vertical line with a branch going right, e.g. `├`
+    pub tee_right: char,
+    /// A vertical line with a branch going left, e.g. `┤`
+    pub tee_left: char,
+    /// A horizontal and vertical line crossing, e.g. `┼`
+    pub cross: char,
+}
+
+impl BorderChars {
+    /// Borders drawn with `-`, `|` and `+`, for terminals or fonts without box-drawing characters
+    pub const ASCII: Self = Self::new(['-', '|', '+', '+', '+', '+', '+', '+', '+', '+', '+']);
+    /// Borders drawn with single thin lines
+    pub const SINGLE: Self = Self::new(['─', '│', '┌', '┐', '└', '┘', '┬', '┴', '├', '┤', '┼']);
+    /// Borders drawn with double lines
+    pub const DOUBLE: Self = Self::new(['═', '║', '╔', '╗', '╚', '╝', '╦', '╩', '╠', '╣', '╬']);
+    /// Borders drawn with single thin lines and rounded corners
+    pub const ROUNDED: Self = Self::new(['─', '│', '╭', '╮', '╰', '╯', '┬', '┴', '├', '┤', '┼']);
+    /// Borders drawn with single thick lines
+    pub const HEAVY: Self = Self::new(['━', '┃', '┏', '┓', '┗', '┛', '┳', '┻', '┣', '┫', '╋']);
+
+    /// Create a new `BorderChars` from an array of characters, in the same order as the struct's fields: horizontal, vertical, top left, top right, bottom left, bottom right, tee down, tee up, tee right, tee left and cross
+    #[must_use]
+    pub const fn new(chars: [char; 11]) -> Self {
+        Self {
+            horizontal: chars[0],
+            vertical: chars[1],
+            top_left: chars[2],
+            top_right: chars[3],
+            bottom_left: chars[4],
+            bottom_right: chars[5],
+            tee_down: chars[6],
+            tee_up: chars[7],
+            tee_right: chars[8],
+            tee_left: chars[9],
+            cross: chars[10],
+        }
+    }
+
+    /// Return the character that connects to the neighbouring cells in the given directions. The bits of `connections` are, from lowest to highest, up, down, left and right
+    const fn char_for_connections(&self, connections: u8) -> char {
+        match connections {
+            // Up, down or both
+            0b0001..=0b0011 => self.vertical,
+            0b1010 => self.top_left,
+            0b0110 => self.top_right,
+            0b1001 => self.bottom_left,
+            0b0101 => self.bottom_right,
+            0b1110 => self.tee_down,
+            0b1101 => self.tee_up,
+            0b1011 => self.tee_right,
+            0b0111 => self.tee_left,
+            0b1111 => self.cross,
+            _ => self.horizontal,
+        }
+    }
+}
+
+/// The style used to draw a [`Frame`]'s border
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderStyle {
+    /// Drawn with `-`, `|` and `+`. See [`BorderChars::ASCII`]
+    Ascii,
+    /// Drawn with single thin lines. See [`BorderChars::SINGLE`]
+    #[default]
+    Single,
+    /// Drawn with double lines. See [`BorderChars::DOUBLE`]
+    Double,
+    /// Drawn with single thin lines and rounded corners. See [`BorderChars::ROUNDED`]
+    Rounded,
+    /// Drawn with single thick lines. See [`BorderChars::HEAVY`]
+    Heavy,
+    /// Drawn with your own set of characters
+    Custom(BorderChars),
+}
+
+impl BorderStyle {
+    /// Return the [`BorderChars`] used to draw the style
+    #[must_use]
+    pub const fn chars(&self) -> BorderChars {
+        match self {
+            Self::Ascii => BorderChars::ASCII,
+            Self::Single => BorderChars::SINGLE,
+            Self::Double => BorderChars::DOUBLE,
+            Self::Rounded => BorderChars::ROUNDED,
+            Self::Heavy => BorderChars::HEAVY,
+            Self::Custom(chars) => *chars,
+        }
+    }
+}
+
+/// A line splitting a [`Frame`] into sections, joined to the frame's border at both ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divider {
+    /// A horizontal line across the frame, at the given number of rows below the top of the frame
+    Horizontal(isize),
+    /// A vertical line down the frame, at the given number of columns right of the left side of the frame
+    Vertical(isize),
+}
+
+/// Collects border lines into cells, so that the correct junction characters can be picked where lines meet
+#[derive(Default)]
+struct BorderCanvas {
+    cells: HashMap<Vec2D, (u8, BorderChars, Modifier)>,
+}
+
+impl BorderCanvas {
+    fn connect(&mut self, pos: Vec2D, connections: u8, chars: BorderChars, modifier: Modifier) {
+        let cell = self.cells.entry(pos).or_insert((0, chars, modifier));
+        *cell = (cell.0 | connections, chars, modifier);
+    }
+
+    fn add_line(
+        &mut self,
+        start: Vec2D,
+        length: isize,
+        horizontal: bool,
+        style: (BorderChars, Modifier),
+    ) {
+        let (step, backward, forward) = if horizontal {
+            (Vec2D::new(1, 0), LEFT, RIGHT)
+        } else {
+            (Vec2D::new(0, 1), UP, DOWN)
+        };
+
+        for i in 0..length {
+            let mut connections = 0;
+            if i > 0 {
+                connections |= backward;
+            }
+            if i < length - 1 {
+                connections |= forward;
+            }
+            if length == 1 {
+                connections = backward | forward;
+            }
+            self.connect(start + step * i, connections, style.0, style.1);
+        }
+    }
+
+    fn add_frame(&mut self, frame: &Frame) {
+        let style = (frame.style.chars(), frame.modifier);
+        let (pos, size) = (frame.pos, frame.size);
+        if size.x <= 0 || size.y <= 0 {
+            return;
+        }
+
+        self.add_line(pos, size.x, true, style);
+        self.add_line(pos + Vec2D::new(0, size.y - 1), size.x, true, style);
+        self.add_line(pos, size.y, false, style);
+        self.add_line(pos + Vec2D::new(size.x - 1, 0), size.y, false, style);
+
+        for divider in &frame.dividers {
+            match divider {
+                Divider::Horizontal(y) => {
+                    self.add_line(pos + Vec2D::new(0, *y), size.x, true, style);
+                }
+                Divider::Vertical(x) => {
+                    self.add_line(pos + Vec2D::new(*x, 0), size.y, false, style);
+                }
+            }
+        }
+    }
+
+    fn pixels(&self) -> Vec<Pixel> {
+        self.cells
+            .iter()
+            .map(|(pos, (connections, chars, modifier))| {
+                Pixel::new(
+                    *pos,
+                    ColChar::new(chars.char_for_connections(*connections), *modifier),
+                )
+            })
+            .collect()
+    }
+}
+
+/// A rectangular border drawn with box-drawing characters, optionally with a title in its top edge and [`Divider`]s splitting it into sections. Only the border is drawn, the inside of the `Frame` is left transparent
+/// ```
+/// use gemini_engine::elements::{ascii::TextAlign, ui::{BorderStyle, Divider, Frame}, view::Modifier, Vec2D};
+///
+/// let frame = Frame::new(Vec2D::ZERO, Vec2D::new(20, 8), BorderStyle::Rounded, Modifier::None)
+///     .with_title("Inventory", TextAlign::Centered)
+///     .with_divider(Divider::Vertical(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The position of the top-left corner of the `Frame`
+    pub pos: Vec2D,
+    /// The size of the `Frame`, including the border
+    pub size: Vec2D,
+    /// The style of the border
+    pub style: BorderStyle,
+    /// A raw [`Modifier`], determining the appearance of the border and title
+    pub modifier: Modifier,
+    /// Text displayed in the top edge of the border
+    pub title: Option<String>,
+    /// How the title is aligned along the top edge
+    pub title_align: TextAlign,
+    /// Lines splitting the frame into sections
+    pub dividers: Vec<Divider>,
+}
+
+impl Frame {
+    /// Create a new `Frame` with no title or dividers
+    #[must_use]
+    pub const fn new(pos: Vec2D, size: Vec2D, style: BorderStyle, modifier: Modifier) -> Self {
+        Self {
+            pos,
+            size,
+            style,
+            modifier,
+            title: None,
+            title_align: TextAlign::Begin,
+            dividers: vec![],
+        }
+    }
+
+    /// Return the `Frame` with a title in its top edge, aligned as specified
+    #[must_use]
+    pub fn with_title(mut self, title: &str, align: TextAlign) -> Self {
+        self.title = Some(String::from(title));
+        self.title_align = align;
+        self
+    }
+
+    /// Return the `Frame` with the divider added
+    #[must_use]
+    pub fn with_divider(mut self, divider: Divider) -> Self {
+        self.dividers.push(divider);
+        self
+    }
+
+    /// The position of the top-left corner of the space inside the border
+    #[must_use]
+    pub fn inner_pos(&self) -> Vec2D {
+        self.pos + Vec2D::new(1, 1)
+    }
+
+    /// The size of the space inside the border
+    #[must_use]
+    pub fn inner_size(&self) -> Vec2D {
+        let inner = self.size - Vec2D::new(2, 2);
+        Vec2D::new(inner.x.max(0), inner.y.max(0))
+    }
+
+    /// Return the pixels of the title, which are drawn over the top edge. The title is padded with a space on either side and cut off if it doesn't fit between the corners
+    fn title_pixels(&self) -> Vec<Pixel> {
+        let Some(title) = &self.title else {
+            return vec![];
+        };
+
+        let space = self.inner_size().x;
+        let padded: Vec<char> = format!(" {title} ")
+            .chars()
+            .take(space.max(0) as usize)
+            .collect();
+        let offset = self.title_align.offset_within(space, padded.len() as isize);
+        let start = self.pos + Vec2D::new(1 + offset, 0);
+
+        (0..)
+            .zip(padded)
+            .map(|(x, c)| Pixel::new(start + Vec2D::new(x, 0), ColChar::new(c, self.modifier)))
+            .collect()
+    }
+}
+
+impl ViewElement for Frame {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let mut canvas = BorderCanvas::default();
+        canvas.add_frame(self);
+
+        let mut pixels = canvas.pixels();
+        pixels.extend(self.title_pixels());
+
+        pixels
+    }
+}
+
+/// A group of [`Frame`]s drawn together, so that where their borders touch or overlap they are joined with the correct junction characters (for example, two frames side by side sharing an edge will have `┬` and `┴` where they meet). Where frames of different styles overlap, the frame added last decides the style
+#[derive(Debug, Clone, Default)]
+pub struct FrameGroup {
+    /// The frames in the group
+    pub frames: Vec<Frame>,
+}
+
+impl FrameGroup {
+    /// Create a new, empty `FrameGroup`
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { frames: vec![] }
+    }
+
+    /// Add a frame to the group
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+}
+
+impl From<Vec<Frame>> for FrameGroup {
+    fn from(frames: Vec<Frame>) -> Self {
+        Self { frames }
+    }
+}
+
+impl ViewElement for FrameGroup {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let mut canvas = BorderCanvas::default();
+        for frame in &self.frames {
+            canvas.add_frame(frame);
+        }
+
+        let mut pixels = canvas.pixels();
+        pixels.extend(self.frames.iter().flat_map(Frame::title_pixels));
+
+        pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::view::utils;
+
+    fn render(element: &impl ViewElement, size: Vec2D) -> Vec<String> {
+        utils::render_rows(&element.active_pixels(), size, ' ')
+    }
+
+    #[test]
+    fn frame_with_dividers_and_title() {
+        let frame = Frame::new(
+            Vec2D::ZERO,
+            Vec2D::new(7, 5),
+            BorderStyle::Single,
+            Modifier::None,
+        )
+        .with_title("Hi", TextAlign::Centered)
+        .with_divider(Divider::Horizontal(2))
+        .with_divider(Divider::Vertical(3));
+
+        assert_eq!(
+            render(&frame, Vec2D::new(7, 5)),
+            vec!["┌ Hi ─┐", "│  │  │", "├──┼──┤", "│  │  │", "└──┴──┘"]
+        );
+    }
+
+    #[test]
+    fn touching_frames_join() {
+        let group = FrameGroup::from(vec![
+            Frame::new(
+                Vec2D::ZERO,
+                Vec2D::new(4, 3),
+                BorderStyle::Double,
+                Modifier::None,
+            ),
+            Frame::new(
+                Vec2D::new(3, 0),
+                Vec2D::new(4, 3),
+                BorderStyle::Double,
+                Modifier::None,
+            ),
+        ]);
+
+        assert_eq!(
+            render(&group, Vec2D::new(7, 3)),
+            vec!["╔══╦══╗", "║  ║  ║", "╚══╩══╝"]
+        );
+    }
+
+    #[test]
+    fn vertical_connections() {
+        let chars = BorderStyle::Single.chars();
+        for connections in [UP, DOWN, UP | DOWN] {
+            assert_eq!(chars.char_for_connections(connections), chars.vertical);
+        }
+        assert_eq!(chars.char_for_connections(LEFT | RIGHT), chars.horizontal);
+    }
+}
@@ -0,0 +1,77 @@
+/// A key on the keyboard, used by [`KeyEvent`]. Gemini doesn't read from the keyboard itself, so you'll need to convert the key events from whichever input library you use into these
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key that types a character
+    Char(char),
+    /// The enter/return key
+    Enter,
+    /// The escape key
+    Escape,
+    /// The backspace key
+    Backspace,
+    /// The delete key
+    Delete,
+    /// The tab key
+    Tab,
+    /// Tab while holding shift
+    BackTab,
+    /// The up arrow key
+    Up,
+    /// The down arrow key
+    Down,
+    /// The left arrow key
+    Left,
+    /// The right arrow key
+    Right,
+    /// The home key
+    Home,
+    /// The end key
+    End,
+    /// The page up key
+    PageUp,
+    /// The page down key
+    PageDown,
+}
+
+/// A key press passed to a [`Widget`](super::Widget), along with whether the control or alt keys were held down at the time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    /// The key that was pressed
+    pub key: Key,
+    /// Whether a control key was held down
+    pub ctrl: bool,
+    /// Whether an alt key was held down
+    pub alt: bool,
+}
+
+impl KeyEvent {
+    /// Create a new `KeyEvent` with no control or alt keys held down
+    #[must_use]
+    pub const fn new(key: Key) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            alt: false,
+        }
+    }
+
+    /// Return the `KeyEvent` with its `ctrl` field set to the chosen value
+    #[must_use]
+    pub const fn with_ctrl(mut self, ctrl: bool) -> Self {
+        self.ctrl = ctrl;
+        self
+    }
+
+    /// Return the `KeyEvent` with its `alt` field set to the chosen value
+    #[must_use]
+    pub const fn with_alt(mut self, alt: bool) -> Self {
+        self.alt = alt;
+        self
+    }
+}
+
+impl From<Key> for KeyEvent {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
@@ -0,0 +1,216 @@
+use crate::elements::{view::Area, Vec2D};
+
+/// The direction in which a [`Layout`] splits an [`Area`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Split the area into columns, from left to right
+    Horizontal,
+    /// Split the area into rows, from top to bottom
+    #[default]
+    Vertical,
+}
+
+/// A rule for how much space a section of a [`Layout`] should take up along the layout's [`Direction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// Exactly this many cells
+    Length(isize),
+    /// A percentage of the available space
+    Percentage(u16),
+    /// A fraction of the available space, given as a numerator and denominator
+    Ratio(u32, u32),
+    /// At least this many cells, growing to take up space left over by the other constraints if there are no [`Constraint::Fill`]s
+    Min(isize),
+    /// At most this many cells. The section takes up its full size before any [`Constraint::Fill`]s share what's left, and only shrinks if there isn't enough space
+    Max(isize),
+    /// Whatever space is left over after all the other constraints, shared with any other `Fill`s in proportion to the given weight
+    Fill(u16),
+}
+
+/// Splits an [`Area`] into rows or columns according to a list of [`Constraint`]s, so that elements can be positioned relative to the size of the [`View`](crate::elements::View) rather than at hard-coded positions. Layouts can be nested by splitting one of the resulting areas again
+///
+/// If the constraints ask for more space than is available, sections are shrunk starting from the last one
+/// ```
+/// use gemini_engine::elements::{ui::{Constraint, Direction, Layout}, view::Area, Vec2D};
+///
+/// let screen = Area::new(Vec2D::ZERO, Vec2D::new(40, 20));
+///
+/// let rows = Layout::new(Direction::Vertical, vec![Constraint::Length(3), Constraint::Fill(1)]).split(screen);
+/// let columns = Layout::new(Direction::Horizontal, vec![Constraint::Percentage(25), Constraint::Fill(1)])
+///     .with_gap(1)
+///     .split(rows[1]);
+///
+/// assert_eq!(rows[0], Area::new(Vec2D::ZERO, Vec2D::new(40, 3)));
+/// assert_eq!(columns[0], Area::new(Vec2D::new(0, 3), Vec2D::new(9, 17)));
+/// assert_eq!(columns[1], Area::new(Vec2D::new(10, 3), Vec2D::new(30, 17)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Layout {
+    /// The direction in which the area is split
+    pub direction: Direction,
+    /// The constraints for each section of the layout, in order
+    pub constraints: Vec<Constraint>,
+    /// Space left empty around the edge of the area before splitting it, `x` on the left and right and `y` on the top and bottom
+    pub margin: Vec2D,
+    /// Space left empty between each section
+    pub gap: isize,
+}
+
+impl Layout {
+    /// Create a new `Layout` with the given direction and constraints, and no margin or gap
+    #[must_use]
+    pub const fn new(direction: Direction, constraints: Vec<Constraint>) -> Self {
+        Self {
+            direction,
+            constraints,
+            margin: Vec2D::ZERO,
+            gap: 0,
+        }
+    }
+
+    /// Return the `Layout` with the chosen margin
+    #[must_use]
+    pub const fn with_margin(mut self, margin: Vec2D) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Return the `Layout` with the chosen gap between sections
+    #[must_use]
+    pub const fn with_gap(mut self, gap: isize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Split the area into one [`Area`] per constraint
+    #[must_use]
+    pub fn split(&self, area: Area) -> Vec<Area> {
+        let inner = area.shrink(self.margin);
+        let (along, across) = match self.direction {
+            Direction::Horizontal => (inner.size.x, inner.size.y),
+            Direction::Vertical => (inner.size.y, inner.size.x),
+        };
+
+        let gap_count = self.constraints.len().saturating_sub(1) as isize;
+        let available = (along - self.gap * gap_count).max(0);
+        let lengths = self.lengths(available);
+
+        let mut offset = 0;
+        lengths
+            .into_iter()
+            .map(|length| {
+                let (pos, size) = match self.direction {
+                    Direction::Horizontal => (Vec2D::new(offset, 0), Vec2D::new(length, across)),
+                    Direction::Vertical => (Vec2D::new(0, offset), Vec2D::new(across, length)),
+                };
+                offset += length + self.gap;
+                Area::new(inner.pos + pos, size)
+            })
+            .collect()
+    }
+
+    /// Resolve the constraints into lengths that fit within the available space
+    fn lengths(&self, available: isize) -> Vec<isize> {
+        let mut lengths: Vec<isize> = self
+            .constraints
+            .iter()
+            .map(|constraint| match *constraint {
+                Constraint::Length(length) | Constraint::Min(length) | Constraint::Max(length) => {
+                    length.max(0)
+                }
+                Constraint::Percentage(percentage) => available * percentage as isize / 100,
+                Constraint::Ratio(_, 0) | Constraint::Fill(_) => 0,
+                Constraint::Ratio(numerator, denominator) => {
+                    (available as i64 * i64::from(numerator) / i64::from(denominator)) as isize
+                }
+            })
+            .collect();
+
+        let mut remaining = available - lengths.iter().sum::<isize>();
+
+        // Shrink from the end if the constraints don't fit
+        for length in lengths.iter_mut().rev() {
+            if remaining >= 0 {
+                break;
+            }
+            let shrink = (*length).min(-remaining);
+            *length -= shrink;
+            remaining += shrink;
+        }
+
+        let fill_weights: Vec<(usize, isize)> = self
+            .constraints
+            .iter()
+            .enumerate()
+            .filter_map(|(i, constraint)| match constraint {
+                Constraint::Fill(weight) => Some((i, *weight as isize)),
+                _ => None,
+            })
+            .collect();
+        let total_weight: isize = fill_weights.iter().map(|(_, weight)| weight).sum();
+
+        if total_weight > 0 {
+            // Share the remaining space by weight, rounding so that nothing is lost
+            let mut cumulative_weight = 0;
+            let mut given = 0;
+            for (i, weight) in fill_weights {
+                cumulative_weight += weight;
+                let target = remaining * cumulative_weight / total_weight;
+                lengths[i] = target - given;
+                given = target;
+            }
+        } else {
+            // Share the remaining space evenly between any `Min`s, giving the first ones an extra cell if it doesn't divide evenly
+            let growable: Vec<usize> = (0..lengths.len())
+                .filter(|i| matches!(self.constraints[*i], Constraint::Min(_)))
+                .collect();
+            let count = (growable.len() as isize).max(1);
+            for (n, i) in (0..).zip(growable) {
+                lengths[i] += remaining / count + isize::from(n < remaining % count);
+            }
+        }
+
+        lengths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lengths(constraints: Vec<Constraint>, length: isize) -> Vec<isize> {
+        Layout::new(Direction::Horizontal, constraints)
+            .split(Area::new(Vec2D::ZERO, Vec2D::new(length, 1)))
+            .iter()
+            .map(|area| area.size.x)
+            .collect()
+    }
+
+    #[test]
+    fn constraint_resolution() {
+        use Constraint::{Fill, Length, Max, Min, Percentage, Ratio};
+
+        assert_eq!(lengths(vec![Length(3), Fill(1), Fill(2)], 12), [3, 3, 6]);
+        assert_eq!(lengths(vec![Percentage(50), Ratio(1, 4)], 20), [10, 5]);
+        assert_eq!(lengths(vec![Min(2), Max(3), Length(1)], 10), [6, 3, 1]);
+        assert_eq!(lengths(vec![Length(6), Length(6)], 8), [6, 2]);
+        assert_eq!(lengths(vec![Max(10), Fill(1)], 30), [10, 20]);
+        assert_eq!(lengths(vec![Max(10), Fill(1)], 6), [6, 0]);
+    }
+
+    #[test]
+    fn margin_and_gap() {
+        let areas = Layout::new(Direction::Vertical, vec![Constraint::Fill(1); 2])
+            .with_margin(Vec2D::new(1, 1))
+            .with_gap(1)
+            .split(Area::new(Vec2D::new(2, 2), Vec2D::new(10, 9)));
+
+        assert_eq!(
+            areas,
+            [
+                Area::new(Vec2D::new(3, 3), Vec2D::new(8, 3)),
+                Area::new(Vec2D::new(3, 7), Vec2D::new(8, 3)),
+            ]
+        );
+    }
+}
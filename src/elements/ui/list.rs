@@ -0,0 +1,262 @@
+use super::{widget::draw_line, Key, KeyEvent, Widget, WidgetEvent, WidgetStyle};
+use crate::elements::{view::ViewElement, Pixel, Vec2D};
+
+/// A scrolling list of items, one of which is selected at a time. Can be used as a menu by responding to the [`WidgetEvent::Activated`] event it reports when enter is pressed. The up and down arrows, home, end, page up and page down keys move the selection, and the list scrolls to keep the selected item in view
+/// ```
+/// use gemini_engine::elements::{ui::{Key, List, Widget, WidgetEvent}, Vec2D};
+///
+/// let mut menu = List::new(Vec2D::ZERO, Vec2D::new(12, 2), vec!["New game", "Continue", "Quit"]);
+///
+/// assert_eq!(menu.handle_key(Key::End.into()), Some(WidgetEvent::Selected(2)));
+/// assert_eq!(menu.handle_key(Key::Enter.into()), Some(WidgetEvent::Activated(2)));
+/// assert_eq!(menu.offset(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct List {
+    /// The position of the top-left corner of the list
+    pub pos: Vec2D,
+    /// The size of the space the list is drawn in. Items are cut off at the width, and the list scrolls if there are more items than the height
+    pub size: Vec2D,
+    /// The items in the list
+    pub items: Vec<String>,
+    /// Drawn before the selected item. Other items are indented by the same amount
+    pub highlight_symbol: String,
+    /// The modifiers used to draw the list. The highlight modifier is used for the selected item
+    pub style: WidgetStyle,
+    selected: usize,
+    offset: usize,
+    focused: bool,
+}
+
+impl List {
+    /// Create a new `List` with the first item selected, `"> "` as the highlight symbol and the default [`WidgetStyle`]
+    #[must_use]
+    pub fn new(pos: Vec2D, size: Vec2D, items: Vec<&str>) -> Self {
+        Self {
+            pos,
+            size,
+            items: items.into_iter().map(String::from).collect(),
+            highlight_symbol: String::from("> "),
+            style: WidgetStyle::default(),
+            selected: 0,
+            offset: 0,
+            focused: false,
+        }
+    }
+
+    /// Return the `List` with the chosen highlight symbol
+    #[must_use]
+    pub fn with_highlight_symbol(mut self, highlight_symbol: &str) -> Self {
+        self.highlight_symbol = String::from(highlight_symbol);
+        self
+    }
+
+    /// Return the `List` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The index of the selected item
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// The index of the first visible item
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        let height = self.size.y.max(1) as usize;
+        self.offset
+            .clamp((self.selected + 1).saturating_sub(height), self.selected)
+    }
+
+    /// Select the item at the index, clamped to the last item, and scroll it into view
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.items.len().saturating_sub(1));
+        self.offset = self.offset();
+    }
+
+    /// Move the selection by the given number of items, returning [`WidgetEvent::Selected`] if it changed
+    fn move_selection(&mut self, amount: isize) -> Option<WidgetEvent> {
+        let previous = self.selected;
+        self.select(self.selected.saturating_add_signed(amount));
+        (self.selected != previous).then_some(WidgetEvent::Selected(self.selected))
+    }
+}
+
+impl ViewElement for List {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let width = self.size.x.max(0) as usize;
+        let indent = " ".repeat(self.highlight_symbol.chars().count());
+
+        (0..self.size.y)
+            .zip(self.items.iter().enumerate().skip(self.offset()))
+            .flat_map(|(y, (i, item))| {
+                let is_selected = i == self.selected;
+                let prefix = if is_selected {
+                    &self.highlight_symbol
+                } else {
+                    &indent
+                };
+                let line: String = format!("{prefix}{item:width$}")
+                    .chars()
+                    .take(width)
+                    .collect();
+
+                draw_line(
+                    self.pos + Vec2D::new(0, y),
+                    &line,
+                    self.style.pick(is_selected),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Widget for List {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        let page = self.size.y.max(1);
+        match key.key {
+            Key::Up => self.move_selection(-1),
+            Key::Down => self.move_selection(1),
+            Key::PageUp => self.move_selection(-page),
+            Key::PageDown => self.move_selection(page),
+            Key::Home => self.move_selection(isize::MIN),
+            Key::End => self.move_selection(isize::MAX),
+            Key::Enter | Key::Char(' ') if !self.items.is_empty() => {
+                Some(WidgetEvent::Activated(self.selected))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+/// A set of options drawn one per line as `(*) option` or `( ) option`, only one of which can be chosen at a time. The up and down arrows move the cursor, which is highlighted while the group is focused, and enter or space chooses the option under it
+#[derive(Debug, Clone)]
+pub struct RadioGroup {
+    /// The position of the top-left corner of the group
+    pub pos: Vec2D,
+    /// The options to choose from
+    pub options: Vec<String>,
+    /// The modifiers used to draw the group. The highlight modifier is used for the option under the cursor while the group is focused
+    pub style: WidgetStyle,
+    selected: usize,
+    cursor: usize,
+    focused: bool,
+}
+
+impl RadioGroup {
+    /// Create a new `RadioGroup` with the chosen option selected, clamped to the last option, and the default [`WidgetStyle`]
+    #[must_use]
+    pub fn new(pos: Vec2D, options: Vec<&str>, selected: usize) -> Self {
+        let mut group = Self {
+            pos,
+            options: options.into_iter().map(String::from).collect(),
+            style: WidgetStyle::default(),
+            selected: 0,
+            cursor: 0,
+            focused: false,
+        };
+        group.select(selected);
+
+        group
+    }
+
+    /// Return the `RadioGroup` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The index of the chosen option
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Choose the option at the index, clamped to the last option, and move the cursor to it
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.options.len().saturating_sub(1));
+        self.cursor = self.selected;
+    }
+}
+
+impl ViewElement for RadioGroup {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        (0..)
+            .zip(self.options.iter().enumerate())
+            .flat_map(|(y, (i, option))| {
+                let mark = if i == self.selected { '*' } else { ' ' };
+                draw_line(
+                    self.pos + Vec2D::new(0, y),
+                    &format!("({mark}) {option}"),
+                    self.style.pick(self.focused && i == self.cursor),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Widget for RadioGroup {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        match key.key {
+            Key::Up => self.cursor = self.cursor.saturating_sub(1),
+            Key::Down => {
+                self.cursor = (self.cursor + 1).min(self.options.len().saturating_sub(1));
+            }
+            Key::Enter | Key::Char(' ') if self.cursor < self.options.len() => {
+                self.selected = self.cursor;
+                return Some(WidgetEvent::Selected(self.selected));
+            }
+            _ => (),
+        }
+
+        None
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_scrolls_to_selection() {
+        let mut list = List::new(Vec2D::ZERO, Vec2D::new(6, 2), vec!["a", "b", "c", "d"]);
+
+        assert_eq!(list.handle_key(Key::Up.into()), None);
+        assert_eq!(
+            list.handle_key(Key::PageDown.into()),
+            Some(WidgetEvent::Selected(2))
+        );
+        assert_eq!(list.offset(), 1);
+
+        let rows: Vec<char> = list
+            .active_pixels()
+            .iter()
+            .map(|pixel| pixel.fill_char.text_char)
+            .collect();
+        assert_eq!(rows.iter().collect::<String>(), "  b   > c   ");
+
+        list.select(0);
+        assert_eq!(list.offset(), 0);
+    }
+}
@@ -0,0 +1,481 @@
+use super::widget::draw_line;
+use crate::elements::{
+    view::{ColChar, Colour, Modifier, ViewElement},
+    Line, Pixel, Vec2D,
+};
+use std::{cmp::Ordering, collections::HashMap};
+
+/// The bit for each dot in a braille character, indexed by `[y][x]` within the 2x4 grid of dots
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// How the points of a [`Series`] are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeriesStyle {
+    /// Consecutive points are joined with lines
+    #[default]
+    Line,
+    /// Each point is drawn on its own
+    Scatter,
+}
+
+/// A set of points plotted on a [`LineChart`] in a single colour
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    /// The name of the series, shown in the chart's legend. Series with empty names are left out of the legend
+    pub name: String,
+    /// The points in the series, as `(x, y)` pairs
+    pub data: Vec<(f64, f64)>,
+    /// The colour of the series
+    pub colour: Colour,
+    /// Whether the points are joined with lines
+    pub style: SeriesStyle,
+}
+
+impl Series {
+    /// Create a new `Series` drawn as a line through its points
+    #[must_use]
+    pub fn line(name: &str, data: Vec<(f64, f64)>, colour: Colour) -> Self {
+        Self {
+            name: String::from(name),
+            data,
+            colour,
+            style: SeriesStyle::Line,
+        }
+    }
+
+    /// Create a new `Series` drawn as unconnected points
+    #[must_use]
+    pub fn scatter(name: &str, data: Vec<(f64, f64)>, colour: Colour) -> Self {
+        Self {
+            style: SeriesStyle::Scatter,
+            ..Self::line(name, data, colour)
+        }
+    }
+}
+
+/// How points and lines are drawn on a [`LineChart`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Marker {
+    /// Each cell is split into a 2x4 grid of braille dots, giving the chart eight times as many points as it has cells
+    #[default]
+    Braille,
+    /// Each point is a whole cell, drawn with the given character
+    Char(char),
+}
+
+impl Marker {
+    /// The number of points per cell in each direction
+    const fn resolution(self) -> Vec2D {
+        match self {
+            Self::Braille => Vec2D::new(2, 4),
+            Self::Char(_) => Vec2D::new(1, 1),
+        }
+    }
+}
+
+/// Return the smallest and largest values, expanded if they are the same so that the range is never empty
+fn auto_bounds(bounds: Option<(f64, f64)>, values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = bounds.unwrap_or_else(|| {
+        values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+    });
+
+    if !min.is_finite() || !max.is_finite() {
+        (0.0, 1.0)
+    } else if min >= max {
+        (min - 1.0, max + 1.0)
+    } else {
+        (min, max)
+    }
+}
+
+/// Format a tick label to one decimal place, leaving off the decimals for whole numbers
+fn format_tick(value: f64) -> String {
+    // Adding zero turns a rounded `-0.0` into `0.0`
+    let value = (value * 10.0).round() / 10.0 + 0.0;
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+/// A chart plotting one or more [`Series`] of `(x, y)` points, either as lines or scattered points, with optional axes, tick labels and a legend. The range of each axis is fitted to the data unless you set it yourself. By default the chart is drawn with braille characters for a finer resolution than whole cells
+/// ```
+/// use gemini_engine::elements::{ui::{LineChart, Series}, view::Colour, Vec2D};
+///
+/// let sine: Vec<(f64, f64)> = (0..50).map(|i| (i as f64 / 10.0, (i as f64 / 10.0).sin())).collect();
+///
+/// let chart = LineChart::new(Vec2D::ZERO, Vec2D::new(40, 12), vec![
+///     Series::line("sin(x)", sine, Colour::rgb(0, 200, 255)),
+/// ])
+/// .with_y_bounds(Some((-1.0, 1.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineChart {
+    /// The position of the top-left corner of the chart
+    pub pos: Vec2D,
+    /// The size of the chart, including its axes and labels
+    pub size: Vec2D,
+    /// The series plotted on the chart. Where series overlap, the last one is drawn on top
+    pub series: Vec<Series>,
+    /// How the points are drawn
+    pub marker: Marker,
+    /// The range of the x axis. If `None`, the range of the data is used
+    pub x_bounds: Option<(f64, f64)>,
+    /// The range of the y axis. If `None`, the range of the data is used
+    pub y_bounds: Option<(f64, f64)>,
+    /// Whether to draw the axes and their tick labels
+    pub show_axes: bool,
+    /// Whether to draw the legend in the top-right corner
+    pub show_legend: bool,
+    /// A raw [`Modifier`], determining the appearance of the axes, labels and legend text
+    pub modifier: Modifier,
+}
+
+impl LineChart {
+    /// Create a new `LineChart` drawn with braille, with axes and a legend and its ranges fitted to the data
+    #[must_use]
+    pub const fn new(pos: Vec2D, size: Vec2D, series: Vec<Series>) -> Self {
+        Self {
+            pos,
+            size,
+            series,
+            marker: Marker::Braille,
+            x_bounds: None,
+            y_bounds: None,
+            show_axes: true,
+            show_legend: true,
+            modifier: Modifier::None,
+        }
+    }
+
+    /// Return the `LineChart` with the chosen marker
+    #[must_use]
+    pub const fn with_marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Return the `LineChart` with the chosen x axis range
+    #[must_use]
+    pub const fn with_x_bounds(mut self, x_bounds: Option<(f64, f64)>) -> Self {
+        self.x_bounds = x_bounds;
+        self
+    }
+
+    /// Return the `LineChart` with the chosen y axis range
+    #[must_use]
+    pub const fn with_y_bounds(mut self, y_bounds: Option<(f64, f64)>) -> Self {
+        self.y_bounds = y_bounds;
+        self
+    }
+
+    /// Return the `LineChart` with its [`show_axes`](LineChart::show_axes) field set to the chosen value
+    #[must_use]
+    pub const fn with_axes(mut self, show_axes: bool) -> Self {
+        self.show_axes = show_axes;
+        self
+    }
+
+    /// Return the `LineChart` with its [`show_legend`](LineChart::show_legend) field set to the chosen value
+    #[must_use]
+    pub const fn with_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+
+    /// Return the ranges of the x and y axes
+    #[must_use]
+    pub fn bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let points = || self.series.iter().flat_map(|series| series.data.iter());
+        (
+            auto_bounds(self.x_bounds, points().map(|point| point.0)),
+            auto_bounds(self.y_bounds, points().map(|point| point.1)),
+        )
+    }
+
+    /// Draw the axes and tick labels, returning the pixels and the position and size of the remaining plot area
+    fn axes(&self) -> (Vec<Pixel>, Vec2D, Vec2D) {
+        if !self.show_axes {
+            return (vec![], self.pos, self.size);
+        }
+
+        let ((x_min, x_max), (y_min, y_max)) = self.bounds();
+        let y_labels = [y_max, (y_min + y_max) / 2.0, y_min].map(format_tick);
+        let label_width = y_labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0) as isize;
+
+        let plot_pos = self.pos + Vec2D::new(label_width + 1, 0);
+        let plot_size = Vec2D::new(
+            (self.size.x - label_width - 1).max(0),
+            (self.size.y - 2).max(0),
+        );
+        let axis_x = plot_pos.x - 1;
+        let axis_y = plot_pos.y + plot_size.y;
+
+        let mut pixels = vec![];
+        for y in 0..plot_size.y {
+            pixels.push(Pixel::new(
+                Vec2D::new(axis_x, plot_pos.y + y),
+                ColChar::new('│', self.modifier),
+            ));
+        }
+        pixels.push(Pixel::new(
+            Vec2D::new(axis_x, axis_y),
+            ColChar::new('└', self.modifier),
+        ));
+        for x in 0..plot_size.x {
+            pixels.push(Pixel::new(
+                Vec2D::new(plot_pos.x + x, axis_y),
+                ColChar::new('─', self.modifier),
+            ));
+        }
+
+        // Tick labels on the y axis, right-aligned against it
+        let y_rows = [0, (plot_size.y - 1) / 2, plot_size.y - 1];
+        for (label, row) in y_labels.iter().zip(y_rows) {
+            let x = axis_x - label.chars().count() as isize;
+            pixels.extend(draw_line(
+                Vec2D::new(x, plot_pos.y + row),
+                label,
+                self.modifier,
+            ));
+        }
+
+        // Tick labels under the x axis, at the start, middle and end
+        let x_labels = [x_min, (x_min + x_max) / 2.0, x_max].map(format_tick);
+        for (i, label) in (0..).zip(&x_labels) {
+            let length = label.chars().count() as isize;
+            let x = match i {
+                0 => 0,
+                1 => (plot_size.x - length) / 2,
+                _ => plot_size.x - length,
+            };
+            pixels.extend(draw_line(
+                Vec2D::new(plot_pos.x + x, axis_y + 1),
+                label,
+                self.modifier,
+            ));
+        }
+
+        (pixels, plot_pos, plot_size)
+    }
+
+    /// Draw the legend in the top-right corner of the plot area
+    fn legend(&self, plot_pos: Vec2D, plot_size: Vec2D) -> Vec<Pixel> {
+        let named: Vec<&Series> = self
+            .series
+            .iter()
+            .filter(|series| !series.name.is_empty())
+            .collect();
+        let width = named
+            .iter()
+            .map(|series| series.name.chars().count())
+            .max()
+            .unwrap_or(0) as isize
+            + 2;
+
+        let mut pixels = vec![];
+        for (y, series) in (0..plot_size.y).zip(named) {
+            let pos = plot_pos + Vec2D::new(plot_size.x - width, y);
+            pixels.push(Pixel::new(
+                pos,
+                ColChar::new('■', Modifier::Colour(series.colour)),
+            ));
+            pixels.extend(draw_line(
+                pos + Vec2D::new(1, 0),
+                &format!(" {:width$}", series.name, width = (width - 2) as usize),
+                self.modifier,
+            ));
+        }
+
+        pixels
+    }
+
+    /// Return the positions of the series' points within the plot area, in units of the marker's resolution. Lines are clipped to the plot area before they are drawn, so points far outside the bounds don't make them any slower to draw
+    fn series_points(&self, series: &Series, plot_size: Vec2D) -> Vec<Vec2D> {
+        let ((x_min, x_max), (y_min, y_max)) = self.bounds();
+        let dots = plot_size * self.marker.resolution() - Vec2D::new(1, 1);
+        let max = (dots.x as f64, dots.y as f64);
+
+        let points: Vec<(f64, f64)> = series
+            .data
+            .iter()
+            .map(|(x, y)| {
+                (
+                    (x - x_min) / (x_max - x_min) * max.0,
+                    (y_max - y) / (y_max - y_min) * max.1,
+                )
+            })
+            .collect();
+        let round = |(x, y): (f64, f64)| Vec2D::new(x.round() as isize, y.round() as isize);
+
+        match series.style {
+            SeriesStyle::Line if points.len() > 1 => points
+                .windows(2)
+                .filter_map(|pair| clip_segment(pair[0], pair[1], max))
+                .flat_map(|(start, end)| Line::draw(round(start), round(end)))
+                .collect(),
+            _ => points
+                .into_iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(round)
+                .collect(),
+        }
+    }
+}
+
+/// Clip the line between the two points to the rectangle from `(0, 0)` to `max` using the Liang-Barsky algorithm, returning `None` if no part of the line is inside it
+fn clip_segment(
+    start: (f64, f64),
+    end: (f64, f64),
+    max: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    if ![start.0, start.1, end.0, end.1]
+        .iter()
+        .all(|v| v.is_finite())
+    {
+        return None;
+    }
+
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let (mut t_start, mut t_end) = (0.0_f64, 1.0_f64);
+    for (p, q) in [
+        (-dx, start.0),
+        (dx, max.0 - start.0),
+        (-dy, start.1),
+        (dy, max.1 - start.1),
+    ] {
+        match p.partial_cmp(&0.0) {
+            Some(Ordering::Less) => t_start = t_start.max(q / p),
+            Some(Ordering::Greater) => t_end = t_end.min(q / p),
+            // The line is parallel to this edge, so it's either entirely inside or entirely outside of it
+            _ if q < 0.0 => return None,
+            _ => (),
+        }
+    }
+
+    (t_start <= t_end).then(|| {
+        (
+            (t_start.mul_add(dx, start.0), t_start.mul_add(dy, start.1)),
+            (t_end.mul_add(dx, start.0), t_end.mul_add(dy, start.1)),
+        )
+    })
+}
+
+impl ViewElement for LineChart {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let (mut pixels, plot_pos, plot_size) = self.axes();
+        let resolution = self.marker.resolution();
+        let in_plot = |point: &Vec2D| {
+            point.x >= 0
+                && point.y >= 0
+                && point.x < plot_size.x * resolution.x
+                && point.y < plot_size.y * resolution.y
+        };
+
+        // Each cell holds its braille bits and the colour of the last series drawn in it
+        let mut cells: HashMap<Vec2D, (u32, Colour)> = HashMap::new();
+        let mut order = vec![];
+        for series in &self.series {
+            for point in self
+                .series_points(series, plot_size)
+                .iter()
+                .filter(|p| in_plot(p))
+            {
+                let cell = *point / resolution;
+                let dot = *point % resolution;
+                let bit = match self.marker {
+                    Marker::Braille => BRAILLE_DOTS[dot.y as usize][dot.x as usize],
+                    Marker::Char(_) => 0,
+                };
+                let entry = cells.entry(cell).or_insert_with(|| {
+                    order.push(cell);
+                    (0, series.colour)
+                });
+                *entry = (entry.0 | bit, series.colour);
+            }
+        }
+
+        pixels.extend(order.into_iter().map(|cell| {
+            let (bits, colour) = cells[&cell];
+            let text_char = match self.marker {
+                Marker::Braille => char::from_u32(0x2800 + bits).unwrap_or(' '),
+                Marker::Char(c) => c,
+            };
+            Pixel::new(
+                plot_pos + cell,
+                ColChar::new(text_char, Modifier::Colour(colour)),
+            )
+        }));
+
+        if self.show_legend {
+            pixels.extend(self.legend(plot_pos, plot_size));
+        }
+
+        pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn braille_line() {
+        let chart = LineChart::new(
+            Vec2D::ZERO,
+            Vec2D::new(2, 1),
+            vec![Series::line(
+                "",
+                vec![(0.0, 0.0), (3.0, 3.0)],
+                Colour::WHITE,
+            )],
+        )
+        .with_axes(false);
+
+        let rendered: String = chart
+            .active_pixels()
+            .iter()
+            .map(|pixel| pixel.fill_char.text_char)
+            .collect();
+
+        // A diagonal from the bottom-left to the top-right dot of a 4x4 grid of dots
+        assert_eq!(rendered, "⡠⠊");
+    }
+
+    #[test]
+    fn lines_are_clipped_to_the_bounds() {
+        // Without clipping, this would try to draw a line trillions of dots long
+        let chart = LineChart::new(
+            Vec2D::ZERO,
+            Vec2D::new(2, 1),
+            vec![Series::line(
+                "",
+                vec![(0.0, 0.0), (1e12, 1e12)],
+                Colour::WHITE,
+            )],
+        )
+        .with_x_bounds(Some((0.0, 3.0)))
+        .with_y_bounds(Some((0.0, 3.0)))
+        .with_axes(false);
+
+        let rendered: String = chart
+            .active_pixels()
+            .iter()
+            .map(|pixel| pixel.fill_char.text_char)
+            .collect();
+        assert_eq!(rendered, "⡠⠊");
+
+        assert_eq!(
+            clip_segment((-2.0, 1.0), (6.0, 1.0), (3.0, 3.0)),
+            Some(((0.0, 1.0), (3.0, 1.0)))
+        );
+        assert_eq!(clip_segment((-2.0, 5.0), (6.0, 5.0), (3.0, 3.0)), None);
+    }
+}
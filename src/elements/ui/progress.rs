@@ -0,0 +1,347 @@
+use super::{widget::draw_line, Direction};
+use crate::elements::{
+    view::{ColChar, Colour, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+use std::time::Duration;
+
+/// Partially filled cells for a horizontal bar, from one eighth to seven eighths
+const HORIZONTAL_EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+/// Partially filled cells for a vertical bar, from one eighth to seven eighths
+const VERTICAL_EIGHTHS: [char; 7] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+
+/// A bar that fills up as progress is made. Horizontal bars fill from left to right and vertical bars from bottom to top. By default the end of the filled section is drawn with partial block characters (`▏▎▍▌▋▊▉` or `▁▂▃▄▅▆▇`), giving the bar a precision of an eighth of a cell
+/// ```
+/// use gemini_engine::elements::{ui::{Direction, ProgressBar}, view::ColChar, Vec2D};
+///
+/// let bar = ProgressBar::new(Vec2D::ZERO, 10, Direction::Horizontal, 0.5)
+///     .with_background(Some(ColChar::SOLID.with_char('░')));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    /// The position of the top-left corner of the bar
+    pub pos: Vec2D,
+    /// The length of the bar in cells, along its direction
+    pub length: isize,
+    /// The width of the bar in cells, across its direction
+    pub thickness: isize,
+    /// The direction the bar is laid out in
+    pub direction: Direction,
+    /// How full the bar is, from 0.0 to 1.0. Values outside of that range are clamped
+    pub progress: f64,
+    /// The [`ColChar`] used for completely filled cells. Its modifier is also used for partially filled cells
+    pub fill: ColChar,
+    /// If set, the filled cells are coloured with a gradient from the first colour at the start of the bar to the second at the end, instead of the fill's modifier
+    pub gradient: Option<(Colour, Colour)>,
+    /// The [`ColChar`] used for empty cells. If `None`, empty cells are left transparent
+    pub background: Option<ColChar>,
+    /// Whether to use partial block characters for the last filled cell. If false, the progress is rounded to the nearest whole cell
+    pub sub_cell: bool,
+}
+
+impl ProgressBar {
+    /// Create a new `ProgressBar` one cell thick, filled with [`ColChar::SOLID`] with a transparent background
+    #[must_use]
+    pub const fn new(pos: Vec2D, length: isize, direction: Direction, progress: f64) -> Self {
+        Self {
+            pos,
+            length,
+            thickness: 1,
+            direction,
+            progress,
+            fill: ColChar::SOLID,
+            gradient: None,
+            background: None,
+            sub_cell: true,
+        }
+    }
+
+    /// Return the `ProgressBar` with the chosen thickness
+    #[must_use]
+    pub const fn with_thickness(mut self, thickness: isize) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Return the `ProgressBar` with the chosen fill
+    #[must_use]
+    pub const fn with_fill(mut self, fill: ColChar) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Return the `ProgressBar` coloured with a gradient between the two colours
+    #[must_use]
+    pub const fn with_gradient(mut self, start: Colour, end: Colour) -> Self {
+        self.gradient = Some((start, end));
+        self
+    }
+
+    /// Return the `ProgressBar` with the chosen background
+    #[must_use]
+    pub const fn with_background(mut self, background: Option<ColChar>) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Return the `ProgressBar` with its [`sub_cell`](ProgressBar::sub_cell) field set to the chosen value
+    #[must_use]
+    pub const fn with_sub_cell(mut self, sub_cell: bool) -> Self {
+        self.sub_cell = sub_cell;
+        self
+    }
+
+    /// Return the [`ColChar`] for the cell `index` cells along the bar, or `None` if it should be left transparent
+    fn cell(&self, index: isize) -> Option<ColChar> {
+        let progress = self.progress.clamp(0.0, 1.0) * self.length as f64;
+        let filled_eighths = if self.sub_cell {
+            (progress * 8.0).round() as isize
+        } else {
+            progress.round() as isize * 8
+        };
+
+        let modifier = self.gradient.map_or(self.fill.modifier, |(start, end)| {
+            let t = index as f64 / (self.length - 1).max(1) as f64;
+            Modifier::Colour(start.lerp(end, t))
+        });
+        let eighths = match self.direction {
+            Direction::Horizontal => HORIZONTAL_EIGHTHS,
+            Direction::Vertical => VERTICAL_EIGHTHS,
+        };
+
+        match (filled_eighths - index * 8).clamp(0, 8) {
+            0 => self.background,
+            8 => Some(self.fill.with_mod(modifier)),
+            partial => Some(ColChar::new(eighths[partial as usize - 1], modifier)),
+        }
+    }
+}
+
+impl ViewElement for ProgressBar {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let mut pixels = vec![];
+        for i in 0..self.length {
+            let Some(fill_char) = self.cell(i) else {
+                continue;
+            };
+            for j in 0..self.thickness {
+                let offset = match self.direction {
+                    Direction::Horizontal => Vec2D::new(i, j),
+                    Direction::Vertical => Vec2D::new(j, self.length - 1 - i),
+                };
+                pixels.push(Pixel::new(self.pos + offset, fill_char));
+            }
+        }
+
+        pixels
+    }
+}
+
+/// A horizontal [`ProgressBar`] with a label centred on top of it. If no label is set, the progress is shown as a percentage
+/// ```
+/// use gemini_engine::elements::{ui::Gauge, view::{ColChar, Colour}, Vec2D};
+///
+/// let mut gauge = Gauge::new(Vec2D::ZERO, 20, 0.25);
+/// gauge.bar = gauge.bar.with_gradient(Colour::rgb(255, 0, 0), Colour::rgb(0, 255, 0));
+///
+/// assert_eq!(gauge.label_text(), "25%");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gauge {
+    /// The bar drawn underneath the label
+    pub bar: ProgressBar,
+    /// The text drawn over the bar. If `None`, the progress is shown as a percentage
+    pub label: Option<String>,
+    /// A raw [`Modifier`], determining the appearance of the label
+    pub label_modifier: Modifier,
+}
+
+impl Gauge {
+    /// Create a new `Gauge` one cell thick, with a percentage label and a `░` background
+    #[must_use]
+    pub const fn new(pos: Vec2D, width: isize, progress: f64) -> Self {
+        Self {
+            bar: ProgressBar::new(pos, width, Direction::Horizontal, progress)
+                .with_background(Some(ColChar::new('░', Modifier::None))),
+            label: None,
+            label_modifier: Modifier::Coded(7),
+        }
+    }
+
+    /// Return the `Gauge` with the chosen label
+    #[must_use]
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the `Gauge` with the chosen label modifier
+    #[must_use]
+    pub const fn with_label_modifier(mut self, label_modifier: Modifier) -> Self {
+        self.label_modifier = label_modifier;
+        self
+    }
+
+    /// Return the text that will be drawn over the bar
+    #[must_use]
+    pub fn label_text(&self) -> String {
+        self.label
+            .clone()
+            .unwrap_or_else(|| format!("{:.0}%", self.bar.progress.clamp(0.0, 1.0) * 100.0))
+    }
+}
+
+impl ViewElement for Gauge {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let label: String = self
+            .label_text()
+            .chars()
+            .take(self.bar.length.max(0) as usize)
+            .collect();
+        let label_length = label.chars().count() as isize;
+        let label_pos = self.bar.pos
+            + Vec2D::new(
+                (self.bar.length - label_length) / 2,
+                (self.bar.thickness - 1) / 2,
+            );
+
+        let mut pixels = self.bar.active_pixels();
+        pixels.extend(draw_line(label_pos, &label, self.label_modifier));
+
+        pixels
+    }
+}
+
+/// A set of frames for a [`Spinner`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerStyle {
+    /// `|/-\`
+    #[default]
+    Line,
+    /// Braille dots circling a cell: `⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`
+    Dots,
+    /// A quarter block moving around a cell: `▖▘▝▗`
+    Quadrants,
+    /// An arc turning around a circle: `◜◠◝◞◡◟`
+    Arc,
+    /// A growing and shrinking bar: `▁▃▄▅▆▇█▇▆▅▄▃`
+    Pulse,
+    /// A dot bouncing between the ends of a bracket: `(●   )`
+    Bounce,
+}
+
+impl SpinnerStyle {
+    /// Return the frames of the style
+    #[must_use]
+    pub const fn frames(&self) -> &'static [&'static str] {
+        match self {
+            Self::Line => &["|", "/", "-", "\\"],
+            Self::Dots => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Self::Quadrants => &["▖", "▘", "▝", "▗"],
+            Self::Arc => &["◜", "◠", "◝", "◞", "◡", "◟"],
+            Self::Pulse => &["▁", "▃", "▄", "▅", "▆", "▇", "█", "▇", "▆", "▅", "▄", "▃"],
+            Self::Bounce => &["(●   )", "( ●  )", "(  ● )", "(   ●)", "(  ● )", "( ●  )"],
+        }
+    }
+}
+
+/// An animated spinner for showing that something is happening. Unlike [`AnimatedSprite`](crate::elements::AnimatedSprite), the frame is chosen from the time that has passed rather than being stepped manually, so the spinner turns at the same speed regardless of your frame rate. Call [`Spinner::update()`] every frame with the time since the last one
+/// ```
+/// use gemini_engine::{elements::{ui::{Spinner, SpinnerStyle}, view::Modifier, Vec2D}, gameloop::Duration};
+///
+/// let mut spinner = Spinner::new(Vec2D::ZERO, SpinnerStyle::Line, Modifier::None);
+///
+/// spinner.update(Duration::from_millis(150));
+/// assert_eq!(spinner.current_frame(), "/");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Spinner {
+    /// The position of the spinner's left edge
+    pub pos: Vec2D,
+    /// The frames of the spinner, each a single line of text
+    pub frames: Vec<String>,
+    /// How long each frame is shown for
+    pub frame_duration: Duration,
+    /// The total time the spinner has been running for
+    pub elapsed: Duration,
+    /// A raw [`Modifier`], determining the appearance of the `Spinner`
+    pub modifier: Modifier,
+}
+
+impl Spinner {
+    /// Create a new `Spinner` with one of the built in [`SpinnerStyle`]s, showing each frame for 100 milliseconds
+    #[must_use]
+    pub fn new(pos: Vec2D, style: SpinnerStyle, modifier: Modifier) -> Self {
+        Self::with_frames(pos, style.frames(), modifier)
+    }
+
+    /// Create a new `Spinner` with your own frames, showing each frame for 100 milliseconds
+    #[must_use]
+    pub fn with_frames(pos: Vec2D, frames: &[&str], modifier: Modifier) -> Self {
+        Self {
+            pos,
+            frames: frames.iter().map(|frame| String::from(*frame)).collect(),
+            frame_duration: Duration::from_millis(100),
+            elapsed: Duration::ZERO,
+            modifier,
+        }
+    }
+
+    /// Return the `Spinner` with the chosen frame duration
+    #[must_use]
+    pub const fn with_frame_duration(mut self, frame_duration: Duration) -> Self {
+        self.frame_duration = frame_duration;
+        self
+    }
+
+    /// Advance the spinner by the time that has passed since the last update
+    pub fn update(&mut self, delta: Duration) {
+        self.elapsed += delta;
+    }
+
+    /// Return the frame that should be displayed for the elapsed time
+    #[must_use]
+    pub fn current_frame(&self) -> &str {
+        if self.frames.is_empty() {
+            return "";
+        }
+
+        let frame_count = self.elapsed.as_nanos() / self.frame_duration.as_nanos().max(1);
+        &self.frames[(frame_count % self.frames.len() as u128) as usize]
+    }
+}
+
+impl ViewElement for Spinner {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        draw_line(self.pos, self.current_frame(), self.modifier)
+            .into_iter()
+            .filter(|pixel| pixel.fill_char.text_char != ' ')
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(element: &impl ViewElement) -> String {
+        element
+            .active_pixels()
+            .iter()
+            .map(|pixel| pixel.fill_char.text_char)
+            .collect()
+    }
+
+    #[test]
+    fn partial_cells() {
+        let bar = ProgressBar::new(Vec2D::ZERO, 4, Direction::Horizontal, 0.55)
+            .with_background(Some(ColChar::SOLID.with_char('░')));
+        assert_eq!(render(&bar), "██▎░");
+
+        let bar = bar.with_sub_cell(false);
+        assert_eq!(render(&bar), "██░░");
+
+        let bar = ProgressBar::new(Vec2D::ZERO, 2, Direction::Vertical, 0.75);
+        assert_eq!(render(&bar), "█▄");
+    }
+}
@@ -0,0 +1,533 @@
+use super::{
+    BorderStyle, Constraint, Direction, Key, KeyEvent, Layout, Widget, WidgetEvent, WidgetStyle,
+};
+use crate::elements::{
+    ascii::{parse_markup, StyledSpan, TextWrap},
+    view::{Area, ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+
+/// The content of a single cell in a [`Table`], which can be coloured with [`StyledSpan`]s
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Cell {
+    /// The text in the cell
+    pub text: String,
+    /// Ranges of characters in the text which should use a different [`Modifier`]
+    pub spans: Vec<StyledSpan>,
+}
+
+impl Cell {
+    /// Create a new `Cell` containing plain text
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: String::from(text),
+            spans: vec![],
+        }
+    }
+
+    /// Create a new `Cell` from text containing style markup. See [`parse_markup()`] for the syntax
+    ///
+    /// # Errors
+    /// Returns an error if the markup is invalid
+    pub fn from_markup(markup: &str) -> Result<Self, String> {
+        let (text, spans) = parse_markup(markup)?;
+        Ok(Self { text, spans })
+    }
+
+    /// The length of the cell's longest line
+    fn width(&self) -> isize {
+        self.text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as isize
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+/// How wide a column in a [`Table`] should be
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnWidth {
+    /// Exactly this many cells
+    Fixed(isize),
+    /// Wide enough to fit the widest cell in the column
+    #[default]
+    Auto,
+    /// A share of whatever space is left after the fixed and auto columns, in proportion to the given weight
+    Proportional(u16),
+}
+
+/// What to do with cells that are too long for their column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellOverflow {
+    /// Cut the text off at the edge of the column
+    Truncate,
+    /// Cut the text off, replacing the last visible character with `…`
+    #[default]
+    Ellipsis,
+    /// Wrap the text onto more lines, making the row taller
+    Wrap,
+}
+
+/// A table of cells with a header row, drawn within a fixed size and separated with box-drawing characters. Column widths can be fixed, fitted to their content or share the remaining space, and the table scrolls vertically and horizontally when its content doesn't fit.
+///
+/// As a [`Widget`], the up and down arrows, page up, page down, home and end move the selected row, the left and right arrows scroll sideways, and enter reports [`WidgetEvent::Activated`] with the selected row
+/// ```
+/// use gemini_engine::elements::{ui::{ColumnWidth, Table}, Vec2D};
+///
+/// let table = Table::new(Vec2D::ZERO, Vec2D::new(30, 6), vec!["Name", "Score"], vec![
+///     vec!["Alice", "120"],
+///     vec!["Bob", "95"],
+/// ])
+/// .with_widths(vec![ColumnWidth::Proportional(1), ColumnWidth::Auto]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table {
+    /// The position of the top-left corner of the table
+    pub pos: Vec2D,
+    /// The size of the space the table is drawn in
+    pub size: Vec2D,
+    /// The cells in the header row. If empty, the table has no header
+    pub headers: Vec<Cell>,
+    /// The rows of the table
+    pub rows: Vec<Vec<Cell>>,
+    /// The width of each column. Columns without a width are [`ColumnWidth::Auto`]
+    pub widths: Vec<ColumnWidth>,
+    /// What to do with cells that are too long for their column
+    pub overflow: CellOverflow,
+    /// The characters used to separate the columns and the header
+    pub border: BorderStyle,
+    /// The modifiers used to draw the table. The highlight modifier is used for the selected row
+    pub style: WidgetStyle,
+    /// A raw [`Modifier`], determining the appearance of the header
+    pub header_modifier: Modifier,
+    /// How many cells the table is scrolled to the right
+    pub scroll_x: isize,
+    selected: Option<usize>,
+    offset: usize,
+    focused: bool,
+}
+
+impl Table {
+    /// Create a new `Table` with auto-sized columns, no row selected and a bold header
+    #[must_use]
+    pub fn new(pos: Vec2D, size: Vec2D, headers: Vec<&str>, rows: Vec<Vec<&str>>) -> Self {
+        Self {
+            pos,
+            size,
+            headers: headers.into_iter().map(Cell::new).collect(),
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(Cell::new).collect())
+                .collect(),
+            widths: vec![],
+            overflow: CellOverflow::default(),
+            border: BorderStyle::default(),
+            style: WidgetStyle::default(),
+            header_modifier: Modifier::Coded(1),
+            scroll_x: 0,
+            selected: None,
+            offset: 0,
+            focused: false,
+        }
+    }
+
+    /// Return the `Table` with the chosen column widths
+    #[must_use]
+    pub fn with_widths(mut self, widths: Vec<ColumnWidth>) -> Self {
+        self.widths = widths;
+        self
+    }
+
+    /// Return the `Table` with the chosen overflow behaviour
+    #[must_use]
+    pub const fn with_overflow(mut self, overflow: CellOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Return the `Table` with the chosen border style
+    #[must_use]
+    pub const fn with_border(mut self, border: BorderStyle) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Return the `Table` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The index of the selected row, if there is one
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select the row at the index, clamped to the last row, and scroll it into view. Pass `None` to clear the selection
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index.map(|i| i.min(self.rows.len().saturating_sub(1)));
+        if let Some(selected) = self.selected {
+            // Find the first row that lets every row up to the selected one fit, the same way `visible_rows()` fills the table
+            let columns = self.columns();
+            let available = self.size.y - self.header_height(&columns);
+            let mut used = 0;
+            let mut first = selected;
+            for (i, row) in self.rows.iter().enumerate().take(selected + 1).rev() {
+                used += self.row_height(row, &columns);
+                if used > available && i < selected {
+                    break;
+                }
+                first = i;
+            }
+            self.offset = self.offset.clamp(first, selected);
+        }
+    }
+
+    /// Scroll the table vertically by the given number of rows
+    pub fn scroll_by(&mut self, rows: isize) {
+        self.offset = self
+            .offset
+            .saturating_add_signed(rows)
+            .min(self.rows.len().saturating_sub(1));
+    }
+
+    /// The number of columns in the table
+    fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the x position and width of each column
+    #[must_use]
+    pub fn columns(&self) -> Vec<(isize, isize)> {
+        let count = self.column_count();
+        let constraints: Vec<Constraint> = (0..count)
+            .map(|i| match self.widths.get(i).copied().unwrap_or_default() {
+                ColumnWidth::Fixed(width) => Constraint::Length(width),
+                ColumnWidth::Auto => Constraint::Length(
+                    self.rows
+                        .iter()
+                        .filter_map(|row| row.get(i))
+                        .chain(self.headers.get(i))
+                        .map(Cell::width)
+                        .max()
+                        .unwrap_or(0),
+                ),
+                ColumnWidth::Proportional(weight) => Constraint::Fill(weight),
+            })
+            .collect();
+
+        // Make room for every column at its full width plus at least one cell for each proportional column, scrolling if that is wider than the table
+        let needed: isize = constraints
+            .iter()
+            .map(|constraint| match constraint {
+                Constraint::Length(width) => *width,
+                _ => 1,
+            })
+            .sum::<isize>()
+            + count.saturating_sub(1) as isize;
+        let width = needed.max(self.size.x);
+
+        Layout::new(Direction::Horizontal, constraints)
+            .with_gap(1)
+            .split(Area::new(Vec2D::ZERO, Vec2D::new(width, 1)))
+            .into_iter()
+            .map(|area| (area.pos.x, area.size.x))
+            .collect()
+    }
+
+    /// Split a cell's text into the lines to be drawn in a column of the given width, keeping track of each character's index for its spans
+    fn cell_lines(&self, cell: &Cell, width: isize) -> Vec<Vec<(usize, char)>> {
+        let width = width.max(0) as usize;
+        if self.overflow == CellOverflow::Wrap {
+            return TextWrap::Word
+                .wrap_indexed(&cell.text, width)
+                .into_iter()
+                .map(|line| line.chars)
+                .collect();
+        }
+
+        let mut line: Vec<(usize, char)> = cell
+            .text
+            .chars()
+            .enumerate()
+            .take_while(|(_, c)| *c != '\n')
+            .collect();
+        if line.len() > width {
+            line.truncate(width);
+            if let (CellOverflow::Ellipsis, Some(last)) = (self.overflow, line.last_mut()) {
+                last.1 = '…';
+            }
+        }
+
+        vec![line]
+    }
+
+    /// The height of the row at the index
+    fn row_height(&self, row: &[Cell], columns: &[(isize, isize)]) -> isize {
+        row.iter()
+            .zip(columns)
+            .map(|(cell, (_, width))| self.cell_lines(cell, *width).len() as isize)
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// The height taken up by the header and its separator
+    fn header_height(&self, columns: &[(isize, isize)]) -> isize {
+        if self.headers.is_empty() {
+            0
+        } else {
+            self.row_height(&self.headers, columns) + 1
+        }
+    }
+
+    /// Return the indices of the rows that fit in the table from the current offset. The first row is always included, even if it's too tall to fit and gets cut off
+    fn visible_rows(&self) -> Vec<usize> {
+        let columns = self.columns();
+        let mut y = self.header_height(&columns);
+        let mut visible = vec![];
+        for (i, row) in self.rows.iter().enumerate().skip(self.offset) {
+            if y >= self.size.y {
+                break;
+            }
+            let height = self.row_height(row, &columns);
+            if y + height <= self.size.y || visible.is_empty() {
+                visible.push(i);
+            }
+            y += height;
+        }
+
+        visible
+    }
+
+    /// Draw a row of cells at the given height, returning the pixels and the height of the row
+    fn draw_row(
+        &self,
+        row: &[Cell],
+        columns: &[(isize, isize)],
+        y: isize,
+        modifier: Modifier,
+        fill_spaces: bool,
+    ) -> (Vec<Pixel>, isize) {
+        let height = self.row_height(row, columns);
+        let chars = self.border.chars();
+        let mut pixels = vec![];
+
+        for (i, (x, width)) in columns.iter().enumerate() {
+            let cell = row.get(i);
+            let lines = cell.map_or_else(Vec::new, |cell| self.cell_lines(cell, *width));
+            for line_y in 0..height {
+                let line = lines.get(line_y as usize);
+                for column_x in 0..*width {
+                    let content = line.and_then(|line| line.get(column_x as usize));
+                    let fill_char = match (content, cell) {
+                        (Some((index, c)), Some(cell)) => ColChar::new(
+                            *c,
+                            if fill_spaces {
+                                modifier
+                            } else {
+                                StyledSpan::modifier_at(&cell.spans, *index, modifier)
+                            },
+                        ),
+                        _ if fill_spaces => ColChar::new(' ', modifier),
+                        _ => continue,
+                    };
+                    pixels.push(Pixel::new(Vec2D::new(x + column_x, y + line_y), fill_char));
+                }
+                if i + 1 < columns.len() {
+                    pixels.push(Pixel::new(
+                        Vec2D::new(x + width, y + line_y),
+                        ColChar::new(chars.vertical, self.style.normal),
+                    ));
+                }
+            }
+        }
+
+        (pixels, height)
+    }
+}
+
+impl ViewElement for Table {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let columns = self.columns();
+        let chars = self.border.chars();
+        let mut pixels = vec![];
+
+        if !self.headers.is_empty() {
+            let (header, header_height) =
+                self.draw_row(&self.headers, &columns, 0, self.header_modifier, false);
+            pixels.extend(header);
+
+            let total_width = columns.last().map_or(0, |(x, width)| x + width);
+            for x in 0..total_width {
+                let is_junction = columns
+                    .iter()
+                    .any(|(column_x, width)| column_x + width == x);
+                pixels.push(Pixel::new(
+                    Vec2D::new(x, header_height),
+                    ColChar::new(
+                        if is_junction {
+                            chars.cross
+                        } else {
+                            chars.horizontal
+                        },
+                        self.style.normal,
+                    ),
+                ));
+            }
+        }
+
+        let mut y = self.header_height(&columns);
+        for i in self.visible_rows() {
+            let is_selected = self.selected == Some(i);
+            let (row, height) = self.draw_row(
+                &self.rows[i],
+                &columns,
+                y,
+                self.style.pick(is_selected),
+                is_selected,
+            );
+            pixels.extend(row);
+            y += height;
+        }
+
+        let area = Area::new(Vec2D::ZERO, self.size);
+        pixels
+            .into_iter()
+            .map(|pixel| Pixel::new(pixel.pos - Vec2D::new(self.scroll_x, 0), pixel.fill_char))
+            .filter(|pixel| area.contains(pixel.pos))
+            .map(|pixel| Pixel::new(self.pos + pixel.pos, pixel.fill_char))
+            .collect()
+    }
+}
+
+impl Widget for Table {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        let previous = self.selected;
+        let selected = self.selected.unwrap_or(0);
+        let page = self.visible_rows().len().max(1);
+
+        match key.key {
+            Key::Up => self.select(Some(selected.saturating_sub(1))),
+            Key::Down if self.selected.is_none() => self.select(Some(0)),
+            Key::Down => self.select(Some(selected + 1)),
+            Key::PageUp => self.select(Some(selected.saturating_sub(page))),
+            Key::PageDown => self.select(Some(selected + page)),
+            Key::Home => self.select(Some(0)),
+            Key::End => self.select(Some(usize::MAX)),
+            Key::Left => self.scroll_x = (self.scroll_x - 1).max(0),
+            Key::Right => {
+                let total_width = self.columns().last().map_or(0, |(x, width)| x + width);
+                self.scroll_x = (self.scroll_x + 1).min((total_width - self.size.x).max(0));
+            }
+            Key::Enter => return self.selected.map(WidgetEvent::Activated),
+            _ => (),
+        }
+
+        if self.rows.is_empty() {
+            self.selected = None;
+        }
+        match self.selected {
+            Some(selected) if self.selected != previous => Some(WidgetEvent::Selected(selected)),
+            _ => None,
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::view::utils;
+
+    fn render(table: &Table) -> Vec<String> {
+        utils::render_rows(&table.active_pixels(), table.size, ' ')
+    }
+
+    #[test]
+    fn column_widths_and_scrolling() {
+        let mut table = Table::new(
+            Vec2D::ZERO,
+            Vec2D::new(12, 4),
+            vec!["Item", "Qty"],
+            vec![vec!["Apple", "3"], vec!["Pear", "12"], vec!["Banana", "7"]],
+        )
+        .with_widths(vec![ColumnWidth::Proportional(1), ColumnWidth::Auto]);
+
+        assert_eq!(table.columns(), [(0, 8), (9, 3)]);
+        assert_eq!(
+            render(&table),
+            [
+                "Item    │Qty",
+                "────────┼───",
+                "Apple   │3  ",
+                "Pear    │12 "
+            ]
+        );
+
+        assert_eq!(
+            table.handle_key(Key::End.into()),
+            Some(WidgetEvent::Selected(2))
+        );
+        assert_eq!(render(&table)[2..], ["Pear    │12 ", "Banana  │7  "]);
+        table.select(Some(1));
+        assert_eq!(render(&table)[2..], ["Pear    │12 ", "Banana  │7  "]);
+        table.select(Some(0));
+        assert_eq!(render(&table)[2..], ["Apple   │3  ", "Pear    │12 "]);
+    }
+
+    #[test]
+    fn wrapped_and_truncated_cells() {
+        let table = Table::new(
+            Vec2D::ZERO,
+            Vec2D::new(7, 3),
+            vec![],
+            vec![vec!["one two", "abcdef"]],
+        )
+        .with_widths(vec![ColumnWidth::Fixed(3); 2]);
+        assert_eq!(render(&table)[0], "on…│ab…");
+
+        let table = table.with_overflow(CellOverflow::Wrap);
+        assert_eq!(render(&table), ["one│abc", "two│def", "       "]);
+    }
+
+    #[test]
+    fn wrapped_header() {
+        let mut table = Table::new(
+            Vec2D::ZERO,
+            Vec2D::new(7, 5),
+            vec!["one two", "x"],
+            vec![vec!["a", "1"], vec!["b", "2"], vec!["c", "3"]],
+        )
+        .with_widths(vec![ColumnWidth::Fixed(3); 2])
+        .with_overflow(CellOverflow::Wrap);
+        assert_eq!(
+            render(&table),
+            ["one│x  ", "two│   ", "───┼───", "a  │1  ", "b  │2  "]
+        );
+
+        table.select(Some(2));
+        assert_eq!(render(&table)[3..], ["b  │2  ", "c  │3  "]);
+    }
+}
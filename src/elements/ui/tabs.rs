@@ -0,0 +1,98 @@
+use super::{widget::draw_line, Key, KeyEvent, Widget, WidgetEvent, WidgetStyle};
+use crate::elements::{view::ViewElement, Pixel, Vec2D};
+
+/// A row of tab titles separated by `|`, with the selected tab highlighted. The left and right arrows switch between tabs, wrapping around at either end
+#[derive(Debug, Clone)]
+pub struct Tabs {
+    /// The position of the left edge of the row of tabs
+    pub pos: Vec2D,
+    /// The title of each tab
+    pub titles: Vec<String>,
+    /// The modifiers used to draw the tabs. The highlight modifier is used for the selected tab
+    pub style: WidgetStyle,
+    selected: usize,
+    focused: bool,
+}
+
+impl Tabs {
+    /// Create a new `Tabs` with the first tab selected and the default [`WidgetStyle`]
+    #[must_use]
+    pub fn new(pos: Vec2D, titles: Vec<&str>) -> Self {
+        Self {
+            pos,
+            titles: titles.into_iter().map(String::from).collect(),
+            style: WidgetStyle::default(),
+            selected: 0,
+            focused: false,
+        }
+    }
+
+    /// Return the `Tabs` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The index of the selected tab
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select the tab at the index, clamped to the last tab
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.titles.len().saturating_sub(1));
+    }
+}
+
+impl ViewElement for Tabs {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let mut pixels = vec![];
+        let mut x = 0;
+        for (i, title) in self.titles.iter().enumerate() {
+            if i > 0 {
+                pixels.extend(draw_line(
+                    self.pos + Vec2D::new(x, 0),
+                    "|",
+                    self.style.normal,
+                ));
+                x += 1;
+            }
+            let title = format!(" {title} ");
+            pixels.extend(draw_line(
+                self.pos + Vec2D::new(x, 0),
+                &title,
+                self.style.pick(i == self.selected),
+            ));
+            x += title.chars().count() as isize;
+        }
+
+        pixels
+    }
+}
+
+impl Widget for Tabs {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        let count = self.titles.len();
+        if count == 0 {
+            return None;
+        }
+
+        self.select(match key.key {
+            Key::Left => (self.selected + count - 1) % count,
+            Key::Right => (self.selected + 1) % count,
+            _ => return None,
+        });
+
+        Some(WidgetEvent::Selected(self.selected))
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,320 @@
+use super::{widget::draw_line, Key, KeyEvent, Widget, WidgetEvent, WidgetStyle};
+use crate::elements::{
+    view::{ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+
+/// A single line text field that can be typed into. While focused it supports the usual editing keys:
+/// - Left and right move the cursor, or jump a word at a time with ctrl held down
+/// - Home and end jump to the start and end of the text
+/// - Backspace and delete remove a character, or a whole word with ctrl held down
+/// - Up and down browse through previously submitted text
+/// - Enter reports [`WidgetEvent::Submitted`] and adds the text to the history
+///
+/// Any edit reports [`WidgetEvent::Changed`]. Text longer than the field scrolls sideways to keep the cursor in view
+/// ```
+/// use gemini_engine::elements::{ui::{Key, KeyEvent, TextInput, Widget, WidgetEvent}, Vec2D};
+///
+/// let mut input = TextInput::new(Vec2D::ZERO, 20).with_placeholder("Your name");
+///
+/// for c in "Ada Lovelace".chars() {
+///     input.handle_key(Key::Char(c).into());
+/// }
+/// input.handle_key(KeyEvent::new(Key::Backspace).with_ctrl(true));
+///
+/// assert_eq!(input.value(), "Ada ");
+/// assert_eq!(input.handle_key(Key::Enter.into()), Some(WidgetEvent::Submitted));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    /// The position of the left edge of the field
+    pub pos: Vec2D,
+    /// The width of the field in cells
+    pub width: isize,
+    /// Text shown in place of the value while the field is empty
+    pub placeholder: String,
+    /// If set, every character of the value is displayed as this character, for passwords
+    pub mask: Option<char>,
+    /// The modifiers used to draw the field. The highlight modifier is used for the cursor
+    pub style: WidgetStyle,
+    /// A raw [`Modifier`], determining the appearance of the placeholder
+    pub placeholder_modifier: Modifier,
+    value: Vec<char>,
+    cursor: usize,
+    scroll: usize,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    draft: String,
+    focused: bool,
+}
+
+impl TextInput {
+    /// Create a new, empty `TextInput` with no placeholder or mask
+    #[must_use]
+    pub const fn new(pos: Vec2D, width: isize) -> Self {
+        Self {
+            pos,
+            width,
+            placeholder: String::new(),
+            mask: None,
+            style: WidgetStyle::new(Modifier::None, Modifier::Coded(7)),
+            placeholder_modifier: Modifier::Coded(2),
+            value: vec![],
+            cursor: 0,
+            scroll: 0,
+            history: vec![],
+            history_index: None,
+            draft: String::new(),
+            focused: false,
+        }
+    }
+
+    /// Return the `TextInput` with the chosen placeholder
+    #[must_use]
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = String::from(placeholder);
+        self
+    }
+
+    /// Return the `TextInput` with the chosen mask character, such as `*` for a password field
+    #[must_use]
+    pub const fn with_mask(mut self, mask: Option<char>) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Return the `TextInput` with the chosen style
+    #[must_use]
+    pub const fn with_style(mut self, style: WidgetStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the text in the field
+    #[must_use]
+    pub fn value(&self) -> String {
+        self.value.iter().collect()
+    }
+
+    /// Replace the text in the field, moving the cursor to the end
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.chars().collect();
+        self.cursor = self.value.len();
+        self.update_scroll();
+    }
+
+    /// Empty the field
+    pub fn clear(&mut self) {
+        self.set_value("");
+    }
+
+    /// The position of the cursor, as the number of characters before it
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Previously submitted values, from oldest to newest
+    #[must_use]
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Scroll the field so that the cursor is visible
+    fn update_scroll(&mut self) {
+        let width = self.width.max(1) as usize;
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        } else if self.cursor >= self.scroll + width {
+            self.scroll = self.cursor + 1 - width;
+        }
+    }
+
+    /// The index of the start of the word before the cursor
+    fn previous_word(&self) -> usize {
+        let mut i = self.cursor;
+        while i > 0 && self.value[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        while i > 0 && !self.value[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        i
+    }
+
+    /// The index of the end of the word after the cursor
+    fn next_word(&self) -> usize {
+        let mut i = self.cursor;
+        while i < self.value.len() && self.value[i].is_whitespace() {
+            i += 1;
+        }
+        while i < self.value.len() && !self.value[i].is_whitespace() {
+            i += 1;
+        }
+        i
+    }
+
+    /// Step through the history, where a negative `direction` goes back to older values
+    fn browse_history(&mut self, direction: isize) {
+        if self.history.is_empty() {
+            return;
+        }
+
+        let index = match (self.history_index, direction < 0) {
+            (None, true) => {
+                self.draft = self.value();
+                Some(self.history.len() - 1)
+            }
+            (None, false) => return,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i + 1 < self.history.len() => Some(i + 1),
+            (Some(_), false) => None,
+        };
+
+        self.history_index = index;
+        let value = index.map_or_else(|| self.draft.clone(), |i| self.history[i].clone());
+        self.set_value(&value);
+    }
+}
+
+impl ViewElement for TextInput {
+    fn active_pixels(&self) -> Vec<Pixel> {
+        let width = self.width.max(0) as usize;
+        let (text, modifier): (String, Modifier) = if self.value.is_empty() {
+            (
+                self.placeholder.chars().take(width).collect(),
+                self.placeholder_modifier,
+            )
+        } else {
+            (
+                self.value
+                    .iter()
+                    .skip(self.scroll)
+                    .take(width)
+                    .map(|c| self.mask.unwrap_or(*c))
+                    .collect(),
+                self.style.normal,
+            )
+        };
+
+        let mut pixels = draw_line(self.pos, &format!("{text:width$}"), modifier);
+        if self.focused && width > 0 {
+            let x = self.cursor - self.scroll;
+            if let Some(pixel) = pixels.get_mut(x) {
+                pixel.fill_char = ColChar::new(pixel.fill_char.text_char, self.style.highlight);
+            }
+        }
+
+        pixels
+    }
+}
+
+impl Widget for TextInput {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent> {
+        let previous = self.value.clone();
+
+        match key.key {
+            Key::Char(c) if !key.ctrl && !key.alt && !c.is_control() => {
+                self.value.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Key::Backspace if key.ctrl => {
+                let start = self.previous_word();
+                self.value.drain(start..self.cursor);
+                self.cursor = start;
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.value.remove(self.cursor);
+            }
+            Key::Delete if key.ctrl => {
+                let end = self.next_word();
+                self.value.drain(self.cursor..end);
+            }
+            Key::Delete if self.cursor < self.value.len() => {
+                self.value.remove(self.cursor);
+            }
+            Key::Left if key.ctrl => self.cursor = self.previous_word(),
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right if key.ctrl => self.cursor = self.next_word(),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.value.len()),
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = self.value.len(),
+            Key::Up => self.browse_history(-1),
+            Key::Down => self.browse_history(1),
+            Key::Enter => {
+                let value = self.value();
+                if !value.is_empty() && self.history.last() != Some(&value) {
+                    self.history.push(value);
+                }
+                self.history_index = None;
+                return Some(WidgetEvent::Submitted);
+            }
+            _ => (),
+        }
+
+        self.update_scroll();
+        (self.value != previous).then_some(WidgetEvent::Changed)
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(input: &mut TextInput, text: &str) {
+        for c in text.chars() {
+            input.handle_key(Key::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn editing_and_scrolling() {
+        let mut input = TextInput::new(Vec2D::ZERO, 5);
+        type_text(&mut input, "hello world");
+        assert_eq!(input.scroll, 7);
+
+        input.handle_key(KeyEvent::new(Key::Left).with_ctrl(true));
+        assert_eq!(input.cursor(), 6);
+        input.handle_key(Key::Home.into());
+        input.handle_key(KeyEvent::new(Key::Delete).with_ctrl(true));
+        assert_eq!(input.value(), " world");
+        assert_eq!(input.scroll, 0);
+
+        let masked = input.with_mask(Some('*'));
+        let rendered: String = masked
+            .active_pixels()
+            .iter()
+            .map(|pixel| pixel.fill_char.text_char)
+            .collect();
+        assert_eq!(rendered, "*****");
+    }
+
+    #[test]
+    fn history() {
+        let mut input = TextInput::new(Vec2D::ZERO, 10);
+        for command in ["first", "second"] {
+            type_text(&mut input, command);
+            input.handle_key(Key::Enter.into());
+            input.clear();
+        }
+        type_text(&mut input, "draft");
+
+        input.handle_key(Key::Up.into());
+        assert_eq!(input.value(), "second");
+        input.handle_key(Key::Up.into());
+        input.handle_key(Key::Up.into());
+        assert_eq!(input.value(), "first");
+        input.handle_key(Key::Down.into());
+        input.handle_key(Key::Down.into());
+        assert_eq!(input.value(), "draft");
+    }
+}
@@ -0,0 +1,140 @@
+use super::{Key, KeyEvent};
+use crate::elements::{
+    view::{ColChar, Modifier, ViewElement},
+    Pixel, Vec2D,
+};
+
+/// Something that happened to a [`Widget`] as a result of a [`KeyEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WidgetEvent {
+    /// A [`Button`](super::Button) was pressed
+    Pressed,
+    /// A [`Checkbox`](super::Checkbox) was toggled, and is now checked if the value is true
+    Toggled(bool),
+    /// The item at the index was selected, for example by moving through a [`List`](super::List) or [`Tabs`](super::Tabs)
+    Selected(usize),
+    /// The item at the index was activated, for example by pressing enter on an item in a [`List`](super::List)
+    Activated(usize),
+    /// The widget's content was changed, for example by typing into a [`TextInput`](super::TextInput)
+    Changed,
+    /// Enter was pressed in a [`TextInput`](super::TextInput)
+    Submitted,
+}
+
+/// The modifiers used to draw a [`Widget`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidgetStyle {
+    /// The modifier for the widget's regular content
+    pub normal: Modifier,
+    /// The modifier for focused buttons and checkboxes and the selected item in a list, radio group or set of tabs
+    pub highlight: Modifier,
+}
+
+impl WidgetStyle {
+    /// Create a new `WidgetStyle` from the normal and highlight modifiers
+    #[must_use]
+    pub const fn new(normal: Modifier, highlight: Modifier) -> Self {
+        Self { normal, highlight }
+    }
+
+    /// Return the highlight modifier if `highlighted` is true, and the normal modifier otherwise
+    #[must_use]
+    pub const fn pick(&self, highlighted: bool) -> Modifier {
+        if highlighted {
+            self.highlight
+        } else {
+            self.normal
+        }
+    }
+}
+
+impl Default for WidgetStyle {
+    /// No modifier for the regular content, and reversed colours for the highlight
+    fn default() -> Self {
+        Self::new(Modifier::None, Modifier::Coded(7))
+    }
+}
+
+/// An interactive [`ViewElement`] that can be focused and controlled with the keyboard. You can manage the focus between several widgets with a [`FocusGroup`]
+pub trait Widget: ViewElement {
+    /// Respond to a key press, returning a [`WidgetEvent`] if it caused anything worth reporting
+    fn handle_key(&mut self, key: KeyEvent) -> Option<WidgetEvent>;
+
+    /// Returns true if the widget currently has keyboard focus
+    fn is_focused(&self) -> bool;
+
+    /// Give or take away keyboard focus
+    fn set_focused(&mut self, focused: bool);
+}
+
+/// Keeps track of which of a set of [`Widget`]s has keyboard focus. [`Key::Tab`] and [`Key::BackTab`] move the focus forwards and backwards, and any other key is passed to the focused widget
+/// ```
+/// use gemini_engine::elements::{ui::{Button, Checkbox, FocusGroup, Key, WidgetEvent}, Vec2D};
+///
+/// let mut start = Button::new(Vec2D::new(0, 0), "Start");
+/// let mut music = Checkbox::new(Vec2D::new(0, 1), "Music", true);
+/// let mut focus = FocusGroup::new();
+///
+/// focus.handle_key(&mut [&mut start, &mut music], Key::Tab.into());
+/// let event = focus.handle_key(&mut [&mut start, &mut music], Key::Enter.into());
+///
+/// assert_eq!(event, Some((1, WidgetEvent::Toggled(false))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FocusGroup {
+    /// The index of the focused widget
+    pub focused: usize,
+}
+
+impl FocusGroup {
+    /// Create a new `FocusGroup` with the first widget focused
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { focused: 0 }
+    }
+
+    /// Move the focus to the widget at the index, wrapping around if it is past the end
+    pub fn focus(&mut self, widgets: &mut [&mut dyn Widget], index: usize) {
+        self.focused = index.checked_rem(widgets.len()).unwrap_or(0);
+        self.sync(widgets);
+    }
+
+    /// Make sure the focused widget, and only the focused widget, has its focus set. This is done automatically by [`FocusGroup::handle_key()`]
+    pub fn sync(&self, widgets: &mut [&mut dyn Widget]) {
+        for (i, widget) in widgets.iter_mut().enumerate() {
+            widget.set_focused(i == self.focused);
+        }
+    }
+
+    /// Handle a key press, moving focus on [`Key::Tab`] and [`Key::BackTab`] or passing it to the focused widget. Returns the index of the widget and the [`WidgetEvent`] it reported, if any
+    pub fn handle_key(
+        &mut self,
+        widgets: &mut [&mut dyn Widget],
+        key: KeyEvent,
+    ) -> Option<(usize, WidgetEvent)> {
+        if widgets.is_empty() {
+            return None;
+        }
+
+        match key.key {
+            Key::Tab => self.focus(widgets, self.focused + 1),
+            Key::BackTab => self.focus(widgets, self.focused + widgets.len() - 1),
+            _ => {
+                self.focus(widgets, self.focused);
+                return widgets[self.focused]
+                    .handle_key(key)
+                    .map(|event| (self.focused, event));
+            }
+        }
+
+        None
+    }
+}
+
+/// Draw a single line of text, including its spaces, so that highlighted widgets are drawn as solid blocks
+pub(super) fn draw_line(pos: Vec2D, content: &str, modifier: Modifier) -> Vec<Pixel> {
+    (0..)
+        .zip(content.chars())
+        .map(|(x, c)| Pixel::new(pos + Vec2D::new(x, 0), ColChar::new(c, modifier)))
+        .collect()
+}
@@ -0,0 +1,178 @@
+use super::{Vec2D, View};
+
+/// A rectangular region of a [`View`], made up of a position (the top-left corner) and a size. Unlike [`Rect`](crate::elements::Rect), an `Area` doesn't draw anything by itself; it's used to lay out elements with [`Layout`](crate::elements::ui::Layout) or clip them with a [`ClipContainer`](crate::elements::containers::ClipContainer)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Area {
+    /// The position of the top-left corner of the `Area`
+    pub pos: Vec2D,
+    /// The width and height of the `Area`
+    pub size: Vec2D,
+}
+
+impl Area {
+    /// Create a new `Area` with the given position and size
+    #[must_use]
+    pub const fn new(pos: Vec2D, size: Vec2D) -> Self {
+        Self { pos, size }
+    }
+
+    /// Create an `Area` covering the whole of the `View`
+    #[must_use]
+    pub const fn from_view(view: &View) -> Self {
+        Self::new(Vec2D::ZERO, view.size())
+    }
+
+    /// Return the smallest `Area` containing every point, or `None` if there are no points
+    #[must_use]
+    pub fn from_points(points: impl IntoIterator<Item = Vec2D>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), p| {
+            (
+                Vec2D::new(min.x.min(p.x), min.y.min(p.y)),
+                Vec2D::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+
+        Some(Self::new(min, max - min + Vec2D::new(1, 1)))
+    }
+
+    /// Return the position just past the bottom-right corner of the `Area`, i.e. `pos + size`
+    #[must_use]
+    pub fn end(&self) -> Vec2D {
+        self.pos + self.size
+    }
+
+    /// Returns true if the `Area` has no width or no height
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.size.x <= 0 || self.size.y <= 0
+    }
+
+    /// Returns true if the position is inside the `Area`
+    #[must_use]
+    pub const fn contains(&self, pos: Vec2D) -> bool {
+        self.pos.x <= pos.x
+            && pos.x < self.pos.x + self.size.x
+            && self.pos.y <= pos.y
+            && pos.y < self.pos.y + self.size.y
+    }
+
+    /// Return the `Area` shrunk by `margin` on every side (`margin.x` on the left and right, `margin.y` on the top and bottom). The size will never go below zero
+    #[must_use]
+    pub fn shrink(&self, margin: Vec2D) -> Self {
+        Self::new(
+            self.pos + margin,
+            Vec2D::new(
+                (self.size.x - margin.x * 2).max(0),
+                (self.size.y - margin.y * 2).max(0),
+            ),
+        )
+    }
+
+    /// Return the `Area` grown by `margin` on every side (`margin.x` on the left and right, `margin.y` on the top and bottom). This is the opposite of [`Area::shrink()`]
+    #[must_use]
+    pub fn expand(&self, margin: Vec2D) -> Self {
+        Self::new(self.pos - margin, self.size + margin * 2)
+    }
+
+    /// Returns true if the other `Area` is entirely inside this one. Empty areas are inside every `Area`
+    #[must_use]
+    pub fn contains_area(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (self.contains(other.pos) && self.contains(other.end() - Vec2D::new(1, 1)))
+    }
+
+    /// Return the part of the `Area` that overlaps with the other `Area`, or `None` if they don't overlap
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (end, other_end) = (self.end(), other.end());
+        let pos = Vec2D::new(self.pos.x.max(other.pos.x), self.pos.y.max(other.pos.y));
+        let end = Vec2D::new(end.x.min(other_end.x), end.y.min(other_end.y));
+        let area = Self::new(pos, end - pos);
+
+        (!area.is_empty()).then_some(area)
+    }
+
+    /// Returns true if the `Area`s share at least one position
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Return the smallest `Area` containing both `Area`s. If either `Area` is empty the other is returned
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        } else if other.is_empty() {
+            return *self;
+        }
+        let (end, other_end) = (self.end(), other.end());
+        let pos = Vec2D::new(self.pos.x.min(other.pos.x), self.pos.y.min(other.pos.y));
+        let end = Vec2D::new(end.x.max(other_end.x), end.y.max(other_end.y));
+
+        Self::new(pos, end - pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{
+        geometry::{ShapeStyle, Stroke},
+        view::{ColChar, ViewElement},
+        Line, Polygon, Rect,
+    };
+
+    #[test]
+    fn area_helpers() {
+        let a = Area::new(Vec2D::ZERO, Vec2D::new(4, 4));
+        let b = Area::new(Vec2D::new(2, 3), Vec2D::new(4, 4));
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Area::new(Vec2D::new(2, 3), Vec2D::new(2, 1)))
+        );
+        assert_eq!(a.union(&b), Area::new(Vec2D::ZERO, Vec2D::new(6, 7)));
+        assert!(!a.contains_area(&b));
+        assert!(a.union(&b).contains_area(&b));
+
+        // Areas that only touch don't overlap
+        let c = Area::new(Vec2D::new(4, 0), Vec2D::new(1, 1));
+        assert!(!a.overlaps(&c));
+        assert_eq!(a.expand(Vec2D::new(1, 0)).intersection(&c), Some(c));
+
+        assert_eq!(
+            Area::from_points([Vec2D::new(3, -1), Vec2D::new(1, 2)]),
+            Some(Area::new(Vec2D::new(1, -1), Vec2D::new(3, 4)))
+        );
+        assert_eq!(Area::from_points([]), None);
+    }
+
+    #[test]
+    fn shape_bounding_boxes_contain_strokes() {
+        let stroke = Stroke::new(ColChar::SOLID).with_width(3);
+        let rect = Rect::new(Vec2D::new(1, 1), Vec2D::new(4, 3), ColChar::SOLID)
+            .with_style(ShapeStyle::fill_and_stroke(ColChar::SOLID, stroke));
+        let line = Line::new(Vec2D::new(5, 0), Vec2D::ZERO, ColChar::SOLID).with_stroke(stroke);
+        let polygon = Polygon::new(
+            vec![Vec2D::new(0, 0), Vec2D::new(6, 2), Vec2D::new(1, 5)],
+            ColChar::SOLID,
+        );
+
+        let elements: [&dyn ViewElement; 3] = [&rect, &line, &polygon];
+        for element in elements {
+            let bounding_box = element
+                .bounding_box()
+                .expect("element should have a bounding box");
+            assert_eq!(
+                Some(bounding_box),
+                Area::from_points(element.active_points())
+            );
+        }
+
+        let empty = Rect::new(Vec2D::ZERO, Vec2D::new(0, 3), ColChar::SOLID);
+        assert_eq!(empty.bounding_box(), None);
+    }
+}
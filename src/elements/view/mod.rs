@@ -5,12 +5,14 @@ use std::{
     io::{self, Write},
 };
 
+mod area;
 mod pixel;
 mod scale_to_fit;
 pub mod utils;
 mod view_element;
 mod wrapping;
 
+pub use area::Area;
 #[allow(deprecated)]
 pub use pixel::{
     colchar::{ColChar, Colour, Modifier},
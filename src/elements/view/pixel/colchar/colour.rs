@@ -68,6 +68,27 @@ impl Colour {
         Self::rgb(v, v, v)
     }
 
+    /// Create a `Colour` from a hex code such as `ff8000` or `#ff8000`. The three digit shorthand (`f80`) is also accepted
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid three or six digit hex code
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let digits: Vec<u8> = hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| format!("`{hex}` is not a valid hex colour"))?;
+
+        match digits.as_slice() {
+            [r, g, b] => Ok(Self::rgb(r * 17, g * 17, b * 17)),
+            [r0, r1, g0, g1, b0, b1] => Ok(Self::rgb(r0 * 16 + r1, g0 * 16 + g1, b0 * 16 + b1)),
+            _ => Err(format!(
+                "`{hex}` is not a valid hex colour, it must have 3 or 6 digits"
+            )),
+        }
+    }
+
     /// Create a `Colour` from an HSV value
     #[must_use]
     pub fn hsv(hue: u8, sat: u8, val: u8) -> Self {
@@ -96,6 +117,20 @@ impl Colour {
             mul_by_f64_to_u8(blue, 255.0),
         )
     }
+
+    /// Return the colour `t` of the way from this colour to `other`, where `t` is between 0.0 (this colour) and 1.0 (`other`)
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix =
+            |a: u8, b: u8| mul_by_f64_to_u8(f64::from(b).mul_add(t, f64::from(a) * (1.0 - t)), 1.0);
+
+        Self::rgb(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
 }
 
 impl Add for Colour {
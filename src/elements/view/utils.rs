@@ -17,6 +17,16 @@ pub fn pixels_to_points(pixels: &[Pixel]) -> Vec<Vec2D> {
     pixels.iter().map(|p| p.pos).collect()
 }
 
+/// Draw the pixels onto a grid of the given size, with `background` in every empty cell, and return the grid's rows as strings. Used by tests to compare what an element draws against the expected text
+#[cfg(test)]
+pub(crate) fn render_rows(pixels: &[Pixel], size: Vec2D, background: char) -> Vec<String> {
+    let mut rows = vec![vec![background; size.x as usize]; size.y as usize];
+    for pixel in pixels {
+        rows[pixel.pos.y as usize][pixel.pos.x as usize] = pixel.fill_char.text_char;
+    }
+    rows.into_iter().map(String::from_iter).collect()
+}
+
 /// Draw a pseudo-line between the independent and dependent positions.
 #[deprecated = "Please use `Triangle::interpolate` instead"]
 #[must_use]
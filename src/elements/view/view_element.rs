@@ -1,4 +1,4 @@
-use super::{utils, Pixel, Vec2D};
+use super::{utils, Area, Pixel, Vec2D};
 
 /// `ViewElement` is a trait that must be implemented by any element that can be blitted to a [`View`](super::View)
 pub trait ViewElement {
@@ -9,4 +9,9 @@ pub trait ViewElement {
     fn active_points(&self) -> Vec<Vec2D> {
         utils::pixels_to_points(&self.active_pixels())
     }
+
+    /// Return the smallest [`Area`] containing every one of the `ViewElement`'s [`active_points()`](ViewElement::active_points()), or `None` if it has no points. Some elements return a larger area, such as [`Text`](crate::elements::Text) including its spaces, but the area always contains every active point. This has a default setting that goes through every active point, but you can set it to something else to make it faster
+    fn bounding_box(&self) -> Option<Area> {
+        Area::from_points(self.active_points())
+    }
 }
@@ -13,7 +13,7 @@ pub enum DisplayMode {
         /// The desired appearance of the points
         fill_char: ColChar,
     },
-    /// `DisplayMode::Wireframe` renders the edges of the meshes, without filling in the shapes. You can choose whether you want to render with backface culling using the [`backface_culling`](DisplayMode::Wireframe::backface_culling) enum parameter
+    /// `DisplayMode::Wireframe` renders the edges of the meshes, without filling in the shapes. You can choose whether you want to render with backface culling using the [`backface_culling`](DisplayMode::Wireframe::backface_culling) enum parameter. How the edges are drawn is chosen with [`Viewport::wireframe_line_mode`](super::Viewport::wireframe_line_mode)
     Wireframe {
         /// Whether or not to enable backface culling (parts of the mesh with faces that are not facing towards the viewport will be removed)
         backface_culling: bool,
@@ -1,6 +1,7 @@
 //! This module is home to the [`Viewport`], which handles the projecting of [`Mesh3D`]s to a format then displayable by a [`View`](crate::elements::View)
 
 use crate::elements::{
+    geometry::{LineMode, Stroke},
     view::{utils, ColChar, Modifier},
     Line, Pixel, PixelContainer, Polygon, Text, Vec2D,
 };
@@ -31,6 +32,8 @@ pub struct Viewport {
     pub character_width_multiplier: f64,
     /// Any face with vertices closer to the viewport than this value will be clipped
     pub clipping_distace: f64,
+    /// How the characters along each edge are chosen when rendering with [`DisplayMode::Wireframe`]. [`LineMode::Fill`] (the default) draws every edge with its face's `ColChar`, while [`LineMode::Ascii`] gives the wireframe a hand-drawn look
+    pub wireframe_line_mode: LineMode,
 }
 
 impl Viewport {
@@ -43,9 +46,17 @@ impl Viewport {
             origin: screen_origin,
             character_width_multiplier: 2.2,
             clipping_distace: 0.3,
+            wireframe_line_mode: LineMode::Fill,
         }
     }
 
+    /// Return the `Viewport` with its [`wireframe_line_mode`](Viewport::wireframe_line_mode) set to the chosen value
+    #[must_use]
+    pub const fn with_wireframe_line_mode(mut self, line_mode: LineMode) -> Self {
+        self.wireframe_line_mode = line_mode;
+        self
+    }
+
     /// Project the [`Vec3D`] on a flat plane using the `Viewport`'s [fov](Viewport::fov) and [`character_width_multiplier`](Viewport::character_width_multiplier)
     fn perspective(&self, pos: Vec3D) -> Vec2D {
         let f = self.fov / pos.z;
@@ -157,12 +168,13 @@ impl Viewport {
                 let screen_faces = self.project_faces(objects, false, backface_culling);
 
                 for face in screen_faces {
+                    let stroke = Stroke::new(face.fill_char).with_mode(self.wireframe_line_mode);
                     for fi in 0..face.screen_points.len() {
                         let (i0, i1) = (
                             face.screen_points[fi],
                             face.screen_points[(fi + 1) % face.screen_points.len()],
                         );
-                        canvas.append_points(&Line::draw(i0, i1), face.fill_char);
+                        canvas.append(&mut stroke.draw(&Line::draw(i0, i1)));
                     }
                 }
             }
@@ -24,10 +24,11 @@ use std::{thread::sleep, time::Duration};
 pub fn sleep_fps(fps: f32, elapsed: Option<Duration>) -> bool {
     let elapsed = elapsed.unwrap_or(Duration::ZERO);
     let frame_length = Duration::from_secs_f32(1.0 / fps);
-    if frame_length > elapsed {
-        sleep(frame_length - elapsed);
-        false
-    } else {
-        true
+    match frame_length.checked_sub(elapsed) {
+        Some(remaining) if !remaining.is_zero() => {
+            sleep(remaining);
+            false
+        }
+        _ => true,
     }
 }
@@ -3,10 +3,11 @@
 //! Go to [`elements`] for a quick start guide.
 //!
 //! ## Crate Structure
-//! This library is made up of three main crates:
+//! This library is made up of four main crates:
 //! - [`gameloop`], which handles the gameloop. See the [`gameloop`] documentation to see how to structure the usual Gemini project.
 //! - [`elements`], which handles the printing of various objects to a [`View`](elements::View), the central object in a Gemini project.
 //! - [`elements3d`], which handles everything 3D-related. Objects that [`elements3d`] converts to a 2d object will then be printed to the screen by a [`View`](elements::View)
+//! - [`physics`], which moves things around with velocity and gravity and stops them when they collide with the level
 
 #[macro_use]
 mod utils;
@@ -15,3 +16,4 @@ pub mod elements;
 #[cfg(feature = "3D")]
 pub mod elements3d;
 pub mod gameloop;
+pub mod physics;
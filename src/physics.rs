@@ -0,0 +1,26 @@
+//! This module holds a lightweight 2D physics system for moving things around a level. A [`KinematicBody`] has a position that can move by less than a cell at a time, a velocity, an acceleration and gravity, and stops when it runs into anything [`Solid`], reporting which sides it's touching with [`Contacts`]
+//! ```
+//! use gemini_engine::{elements::{collision::CollisionWorld, geometry::Rect, view::ColChar, Vec2D}, gameloop::Duration, physics::{KinematicBody, Vec2F}};
+//!
+//! let mut level = CollisionWorld::new();
+//! level.insert(&Rect::new(Vec2D::new(0, 15), Vec2D::new(40, 1), ColChar::SOLID));
+//!
+//! let mut player = KinematicBody::new(Vec2F::new(5.0, 5.0), Vec2D::new(2, 2))
+//!     .with_gravity(Vec2F::new(0.0, 50.0))
+//!     .with_max_step(1);
+//!
+//! // --inside the gameloop--
+//! let contacts = player.step(Duration::from_secs_f64(1.0 / 30.0), &level);
+//! if contacts.on_ground {
+//!     player.velocity.y = -20.0; // Jump
+//! }
+//! ```
+
+mod vec2f;
+pub use vec2f::Vec2F;
+
+mod solid;
+pub use solid::Solid;
+
+mod body;
+pub use body::{Contacts, KinematicBody};
@@ -0,0 +1,313 @@
+use super::{Solid, Vec2F};
+use crate::elements::{view::Area, Vec2D};
+use std::time::Duration;
+
+/// Which sides of a [`KinematicBody`] were touching something solid at the end of its last step
+#[allow(clippy::struct_excessive_bools)] // Each side can be touched independently of the others
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Contacts {
+    /// Something solid is directly below the body
+    pub on_ground: bool,
+    /// Something solid is directly above the body
+    pub on_ceiling: bool,
+    /// Something solid is directly to the left of the body
+    pub on_left_wall: bool,
+    /// Something solid is directly to the right of the body
+    pub on_right_wall: bool,
+}
+
+impl Contacts {
+    /// Returns true if the body is touching a wall on either side
+    #[must_use]
+    pub const fn on_wall(&self) -> bool {
+        self.on_left_wall || self.on_right_wall
+    }
+}
+
+/// A rectangular body that moves with a velocity, acceleration and gravity, and stops when it hits something [`Solid`]. Its position is stored with `f64`s so that it can move by less than a cell each step, and it's drawn at [`KinematicBody::cell()`]
+///
+/// Each step the body moves along the x axis and then the y axis, going one cell at a time so that it can't skip through thin walls however fast it goes. If [`max_step`](KinematicBody::max_step) is above zero, a body on the ground will also walk up and down slopes and stairs of up to that many cells per column
+/// ```
+/// use gemini_engine::{elements::Vec2D, gameloop::Duration, physics::{KinematicBody, Vec2F}};
+/// use std::collections::HashSet;
+///
+/// let floor: HashSet<Vec2D> = (0..20).map(|x| Vec2D::new(x, 10)).collect();
+/// let mut player = KinematicBody::new(Vec2F::new(2.0, 0.0), Vec2D::new(1, 2))
+///     .with_gravity(Vec2F::new(0.0, 60.0));
+///
+/// for _ in 0..30 {
+///     player.step(Duration::from_secs_f64(1.0 / 30.0), &floor);
+/// }
+///
+/// assert!(player.contacts().on_ground);
+/// assert_eq!(player.cell(), Vec2D::new(2, 8));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KinematicBody {
+    /// The position of the top-left corner of the body
+    pub pos: Vec2F,
+    /// The width and height of the body in cells
+    pub size: Vec2D,
+    /// How far the body moves each second
+    pub velocity: Vec2F,
+    /// How much the velocity changes each second, on top of gravity. Set this from player input or other forces
+    pub acceleration: Vec2F,
+    /// How much the velocity changes each second due to gravity
+    pub gravity: Vec2F,
+    /// The fastest the body can fall, in cells per second
+    pub max_fall_speed: f64,
+    /// The highest step or slope in cells the body can walk up or down without jumping. This is 0 by default
+    pub max_step: isize,
+    contacts: Contacts,
+}
+
+impl KinematicBody {
+    /// Create a new still `KinematicBody` with no gravity, no maximum fall speed and no step height
+    #[must_use]
+    pub const fn new(pos: Vec2F, size: Vec2D) -> Self {
+        Self {
+            pos,
+            size,
+            velocity: Vec2F::ZERO,
+            acceleration: Vec2F::ZERO,
+            gravity: Vec2F::ZERO,
+            max_fall_speed: f64::INFINITY,
+            max_step: 0,
+            contacts: Contacts {
+                on_ground: false,
+                on_ceiling: false,
+                on_left_wall: false,
+                on_right_wall: false,
+            },
+        }
+    }
+
+    /// Return the `KinematicBody` with the chosen velocity
+    #[must_use]
+    pub const fn with_velocity(mut self, velocity: Vec2F) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Return the `KinematicBody` with the chosen gravity
+    #[must_use]
+    pub const fn with_gravity(mut self, gravity: Vec2F) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Return the `KinematicBody` with the chosen maximum fall speed
+    #[must_use]
+    pub const fn with_max_fall_speed(mut self, max_fall_speed: f64) -> Self {
+        self.max_fall_speed = max_fall_speed;
+        self
+    }
+
+    /// Return the `KinematicBody` with the chosen maximum step height
+    #[must_use]
+    pub const fn with_max_step(mut self, max_step: isize) -> Self {
+        self.max_step = max_step;
+        self
+    }
+
+    /// Return the cell the top-left corner of the body is in. Draw the body's sprite here
+    #[must_use]
+    pub fn cell(&self) -> Vec2D {
+        self.pos.floor()
+    }
+
+    /// Return the cells the body covers
+    #[must_use]
+    pub fn area(&self) -> Area {
+        Area::new(self.cell(), self.size)
+    }
+
+    /// Return which sides of the body were touching something solid at the end of its last step
+    #[must_use]
+    pub const fn contacts(&self) -> Contacts {
+        self.contacts
+    }
+
+    /// Returns true if the body would fit with its top-left corner in the cell without overlapping anything solid
+    pub fn fits(&self, cell: Vec2D, solid: &(impl Solid + ?Sized)) -> bool {
+        let size = Vec2D::new(self.size.x.max(1), self.size.y.max(1));
+        (0..size.y).all(|y| (0..size.x).all(|x| !solid.is_solid(cell + Vec2D::new(x, y))))
+    }
+
+    /// Move the body forward by the time that has passed since the last step, usually the time between frames, and return its [`Contacts`]. Velocity pointing into anything the body is touching is cancelled, so a body on the ground stops falling and a body that hits a wall stops moving into it
+    pub fn step(&mut self, delta: Duration, solid: &(impl Solid + ?Sized)) -> Contacts {
+        let delta = delta.as_secs_f64();
+        let was_on_ground = self.contacts.on_ground;
+
+        self.velocity += (self.acceleration + self.gravity) * delta;
+        self.velocity.y = self.velocity.y.min(self.max_fall_speed);
+        let motion = self.velocity * delta;
+
+        self.move_x(motion.x, was_on_ground, solid);
+        self.move_y(motion.y, solid);
+        if was_on_ground && motion.x != 0.0 && self.velocity.y >= 0.0 {
+            self.step_down(solid);
+        }
+
+        self.update_contacts(solid);
+        self.contacts
+    }
+
+    /// Move along the x axis one cell at a time, climbing up steps if the body was on the ground
+    fn move_x(&mut self, distance: f64, can_climb: bool, solid: &(impl Solid + ?Sized)) {
+        let target = self.pos.x + distance;
+        let target_x = target.floor() as isize;
+        let mut cell = self.cell();
+        let direction = (target_x - cell.x).signum();
+
+        while cell.x != target_x {
+            let next = Vec2D::new(cell.x + direction, cell.y);
+            if self.fits(next, solid) {
+                cell = next;
+                continue;
+            }
+
+            let climb = can_climb
+                .then(|| self.climb_height(cell, direction, solid))
+                .flatten();
+            if let Some(height) = climb {
+                cell = next - Vec2D::new(0, height);
+                self.pos.y -= height as f64;
+            } else {
+                self.pos.x = cell.x as f64;
+                self.velocity.x = 0.0;
+                return;
+            }
+        }
+
+        self.pos.x = target;
+    }
+
+    /// Return how far the body would have to climb to move one cell sideways in the direction from the cell, if it's no more than [`max_step`](KinematicBody::max_step)
+    fn climb_height(
+        &self,
+        cell: Vec2D,
+        direction: isize,
+        solid: &(impl Solid + ?Sized),
+    ) -> Option<isize> {
+        let next = Vec2D::new(cell.x + direction, cell.y);
+        (1..=self.max_step).find(|height| {
+            (1..=*height).all(|h| self.fits(cell - Vec2D::new(0, h), solid))
+                && self.fits(next - Vec2D::new(0, *height), solid)
+        })
+    }
+
+    /// Move along the y axis one cell at a time
+    fn move_y(&mut self, distance: f64, solid: &(impl Solid + ?Sized)) {
+        let target = self.pos.y + distance;
+        let target_y = target.floor() as isize;
+        let mut cell = self.cell();
+        let direction = (target_y - cell.y).signum();
+
+        while cell.y != target_y {
+            let next = Vec2D::new(cell.x, cell.y + direction);
+            if !self.fits(next, solid) {
+                self.pos.y = cell.y as f64;
+                self.velocity.y = 0.0;
+                return;
+            }
+            cell = next;
+        }
+
+        self.pos.y = target;
+    }
+
+    /// Keep a body that was walking along the ground on it when the ground drops away by no more than [`max_step`](KinematicBody::max_step), so that it walks down slopes instead of falling off each step
+    fn step_down(&mut self, solid: &(impl Solid + ?Sized)) {
+        let cell = self.cell();
+        if !self.fits(cell + Vec2D::new(0, 1), solid) {
+            return;
+        }
+
+        let drop = (1..=self.max_step).find(|height| {
+            self.fits(cell + Vec2D::new(0, *height), solid)
+                && !self.fits(cell + Vec2D::new(0, height + 1), solid)
+        });
+        if let Some(height) = drop {
+            self.pos.y += height as f64;
+        }
+    }
+
+    fn update_contacts(&mut self, solid: &(impl Solid + ?Sized)) {
+        let cell = self.cell();
+        let blocked = |x, y| !self.fits(cell + Vec2D::new(x, y), solid);
+        let on_ground = blocked(0, 1);
+        // A step the body can climb isn't a wall
+        let wall = |direction| {
+            blocked(direction, 0)
+                && !(on_ground && self.climb_height(cell, direction, solid).is_some())
+        };
+        self.contacts = Contacts {
+            on_ground,
+            on_ceiling: blocked(0, -1),
+            on_left_wall: wall(-1),
+            on_right_wall: wall(1),
+        };
+
+        if (self.contacts.on_ground && self.velocity.y > 0.0)
+            || (self.contacts.on_ceiling && self.velocity.y < 0.0)
+        {
+            self.velocity.y = 0.0;
+        }
+        if (self.contacts.on_right_wall && self.velocity.x > 0.0)
+            || (self.contacts.on_left_wall && self.velocity.x < 0.0)
+        {
+            self.velocity.x = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn walking_up_stairs_and_into_walls() {
+        // A floor with a step up at x = 5 and a wall at x = 10
+        let mut level: HashSet<Vec2D> = (0..5).map(|x| Vec2D::new(x, 5)).collect();
+        level.extend((5..12).map(|x| Vec2D::new(x, 4)));
+        level.extend((0..4).map(|y| Vec2D::new(10, y)));
+
+        let frame = Duration::from_millis(50);
+        let mut body = KinematicBody::new(Vec2F::new(1.0, 0.0), Vec2D::new(1, 2))
+            .with_gravity(Vec2F::new(0.0, 40.0))
+            .with_max_step(1);
+        for _ in 0..20 {
+            body.step(frame, &level);
+        }
+        assert_eq!(body.cell(), Vec2D::new(1, 3));
+        assert!(body.contacts().on_ground);
+        assert!(body.velocity.y.abs() < f64::EPSILON);
+
+        body.velocity.x = 10.0;
+        for _ in 0..20 {
+            body.step(frame, &level);
+        }
+        assert_eq!(body.cell(), Vec2D::new(9, 2));
+        assert!(body.contacts().on_right_wall);
+        assert!(body.velocity.x.abs() < f64::EPSILON);
+
+        // Walk back down the step without leaving the ground
+        body.velocity.x = -10.0;
+        for _ in 0..10 {
+            assert!(body.step(frame, &level).on_ground);
+        }
+        assert_eq!(body.cell(), Vec2D::new(4, 3));
+
+        // Without a step height the body stops at the step instead
+        let mut body = KinematicBody::new(Vec2F::new(1.0, 3.0), Vec2D::new(1, 2))
+            .with_gravity(Vec2F::new(0.0, 40.0))
+            .with_velocity(Vec2F::new(10.0, 0.0));
+        for _ in 0..20 {
+            body.step(frame, &level);
+        }
+        assert_eq!(body.cell(), Vec2D::new(4, 3));
+        assert!(body.contacts().on_wall());
+    }
+}
@@ -0,0 +1,46 @@
+use crate::elements::{
+    collision::CollisionWorld, containers::CollisionContainer, tilemap::Tilemap, Vec2D,
+};
+use std::{collections::HashSet, hash::BuildHasher};
+
+/// Anything a [`KinematicBody`](super::KinematicBody) can collide with, such as a [`CollisionWorld`], a [`Tilemap`] or a set of solid cells. Slices of solids are solid wherever any of them are, and you can implement this for your own level types to have bodies collide with them
+pub trait Solid {
+    /// Returns true if the cell at the position blocks movement
+    fn is_solid(&self, pos: Vec2D) -> bool;
+}
+
+impl Solid for CollisionWorld {
+    fn is_solid(&self, pos: Vec2D) -> bool {
+        self.contains(pos)
+    }
+}
+
+impl Solid for CollisionContainer<'_> {
+    fn is_solid(&self, pos: Vec2D) -> bool {
+        self.contains(pos)
+    }
+}
+
+impl Solid for Tilemap {
+    fn is_solid(&self, pos: Vec2D) -> bool {
+        Self::is_solid(self, pos)
+    }
+}
+
+impl<S: BuildHasher> Solid for HashSet<Vec2D, S> {
+    fn is_solid(&self, pos: Vec2D) -> bool {
+        self.contains(&pos)
+    }
+}
+
+impl<S: Solid> Solid for [S] {
+    fn is_solid(&self, pos: Vec2D) -> bool {
+        self.iter().any(|solid| solid.is_solid(pos))
+    }
+}
+
+impl<S: Solid + ?Sized> Solid for &S {
+    fn is_solid(&self, pos: Vec2D) -> bool {
+        (**self).is_solid(pos)
+    }
+}
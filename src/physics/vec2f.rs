@@ -0,0 +1,68 @@
+use crate::elements::Vec2D;
+use std::{
+    cmp::PartialEq,
+    fmt::{Display, Result},
+};
+
+/// A pair of `f64` used for positions, velocities and accelerations that need to move by less than a whole cell at a time
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2F {
+    /// X-coordinate
+    pub x: f64,
+    /// Y-coordinate
+    pub y: f64,
+}
+
+impl Vec2F {
+    impl_vec_single_value_const!(Vec2F, ZERO, 0.0, (x, y));
+
+    impl_vec_core!(Vec2F, f64, (x, y));
+
+    /// The length/magnitude of the `Vec2F`
+    #[must_use]
+    pub fn magnitude(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// Return the cell the `Vec2F` is in, rounding each coordinate down
+    #[must_use]
+    pub fn floor(&self) -> Vec2D {
+        Vec2D::new(self.x.floor() as isize, self.y.floor() as isize)
+    }
+
+    /// Return the nearest cell to the `Vec2F`
+    #[must_use]
+    pub fn round(&self) -> Vec2D {
+        Vec2D::new(self.x.round() as isize, self.y.round() as isize)
+    }
+}
+
+impl Display for Vec2F {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
+        write!(f, "Vec2F({}, {})", self.x, self.y)
+    }
+}
+
+impl From<Vec2D> for Vec2F {
+    fn from(value: Vec2D) -> Self {
+        Self::new(value.x as f64, value.y as f64)
+    }
+}
+
+impl<T: Into<f64>> From<(T, T)> for Vec2F {
+    fn from(value: (T, T)) -> Self {
+        Self {
+            x: value.0.into(),
+            y: value.1.into(),
+        }
+    }
+}
+
+impl_vec_add!(Vec2F, (x, y));
+impl_vec_sub!(Vec2F, (x, y));
+impl_vec_neg!(Vec2F, 0.0, (x, y));
+impl_vec_mul!(Vec2F, (x, y));
+impl_vec_mul_single!(Vec2F, f64, (x, y));
+impl_vec_div!(Vec2F, (x, y));
+impl_vec_div_single!(Vec2F, f64, (x, y));
+impl_vec_rem!(Vec2F, (x, y));